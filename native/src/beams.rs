@@ -1,32 +1,41 @@
 use crate::{
+    config::BeamStyle,
+    emitter_items::PlacementPreview,
     global::{GlobalMtx, Tier},
     jvm::*,
     mapping_base::MBOptExt,
     objs,
-    packets::S2C,
+    packets::{PacketLimiter, S2C},
     ti,
     util::{
+        client::{new_block_sound, play_sound, stop_sound, Smoothed},
         geometry::{block_to_chunk, write_block_pos, write_vec3d, CoveringBlocks, GeomExt},
         tile::TileExt,
     },
 };
 use alloc::vec::Vec;
-use core::{f32::consts::TAU, mem::take, num::NonZeroUsize};
+use core::{f32::consts::TAU, ffi::CStr, mem::take, num::NonZeroUsize};
 use hashbrown::{hash_map, hash_set, hash_table, HashMap, HashSet, HashTable};
-use nalgebra::{Point2, Point3, UnitVector3, Vector3};
+use nalgebra::{Point2, Point3, Unit, UnitVector3, Vector3, Vector4};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
 pub struct ClientBeam {
     tier: u8,
     active: bool,
+    idle: bool,
     src: Point3<i32>,
     dst: Point3<f64>,
 }
 
 #[derive(Default)]
 pub struct ClientState {
-    pub beams: HashMap<NonZeroUsize, ClientBeam>,
+    pub beams: HashMap<NonZeroUsize, (ClientBeam, Smoothed<Point3<f64>>)>,
+    hums: HashMap<NonZeroUsize, GlobalRef<'static>>,
+    ticks: usize,
+    pub hide_beams: bool,
+    pub beam_style: BeamStyle,
+    pub placement_preview: Option<PlacementPreview>,
 }
 
 pub struct PlayerState {
@@ -47,6 +56,7 @@ pub struct BeamState {
     dst: Point3<f64>,
     pub hit: Option<(Point3<i32>, u8)>,
     pub active: bool,
+    pub idle: bool,
     pub dirty: bool,
 }
 
@@ -72,11 +82,18 @@ pub struct ServerState {
     pub players: HashTable<PlayerState>,
     pub beams: HashMap<NonZeroUsize, BeamState>,
     next_beam_id: NonZeroUsize,
+    pub packet_limiters: HashMap<u128, PacketLimiter>,
 }
 
 impl Default for ServerState {
     fn default() -> Self {
-        Self { dims: HashTable::new(), players: HashTable::new(), beams: HashMap::new(), next_beam_id: NonZeroUsize::new(1).unwrap() }
+        Self {
+            dims: HashTable::new(),
+            players: HashTable::new(),
+            beams: HashMap::new(),
+            next_beam_id: NonZeroUsize::new(1).unwrap(),
+            packet_limiters: HashMap::new(),
+        }
     }
 }
 
@@ -168,7 +185,7 @@ impl BeamState {
 
     fn send_del_beam<'a>(id: NonZeroUsize, player: &impl JRef<'a>) { objs().net_defs.send_s2c(player, &S2C::DelBeam { id }) }
     fn send_set_beam<'a>(&self, id: NonZeroUsize, player: &impl JRef<'a>) {
-        let data = ClientBeam { tier: self.tier, active: self.active, src: self.src, dst: self.dst };
+        let data = ClientBeam { tier: self.tier, active: self.active, idle: self.idle, src: self.src, dst: self.dst };
         objs().net_defs.send_s2c(player, &S2C::SetBeam { id, data })
     }
 
@@ -180,40 +197,17 @@ impl BeamState {
 
     // Will send DelBeam to players that can no longer see the beam, but not SetBeam.
     pub fn recompute(&mut self, jni: &'static JNI, players: &mut HashTable<PlayerState>, dim: &mut DimState, id: NonZeroUsize) {
-        let mv = &objs().mv;
         let old_chunks = take(&mut self.chunks);
         for pos in self.blocks.drain(..) {
             dim.untrack_block(pos, id)
         }
         self.chunks.insert(block_to_chunk(self.src));
-        let mut covering = CoveringBlocks::new(self.src, Vector3::from_element(0.5), self.dir.cast());
-        let j_src = write_vec3d(jni, covering.pos.cast::<f64>().map(|x| x + 0.5));
         let level = self.level.0.with_jni(jni);
-        let chunk_source = level.level_get_chunk_source();
-        loop {
-            covering.step();
-            let chunk = block_to_chunk(covering.pos);
-            self.chunks.insert(chunk);
-            self.blocks.push(covering.pos);
-            dim.track_block(covering.pos, id);
-            let Some(Some(chunk)) = (!level.is_outside_build_height(covering.pos.y)).then(|| chunk_source.loaded_chunk_at(chunk)) else {
-                self.dst = covering.pos.cast::<f64>() + covering.frac;
-                self.hit = None;
-                break;
-            };
-            let pos = write_block_pos(jni, covering.pos);
-            let state = chunk.block_state_at(&pos);
-            let args = [chunk.raw, pos.raw, mv.collision_ctx_empty.raw];
-            let shape = state.call_object_method(mv.block_state_get_visual_shape, &args).unwrap().unwrap();
-            let j_dst = write_vec3d(jni, (covering.pos.cast::<f64>() + covering.frac + *covering.dir * 2.).cast());
-            if let Some(hit) = shape.call_object_method(mv.voxel_shape_clip, &[j_src.raw, j_dst.raw, pos.raw]).unwrap() {
-                if !hit.get_bool_field(mv.block_hit_result_miss) {
-                    self.dst = hit.get_object_field(mv.block_hit_result_pos).unwrap().read_vec3d().cast();
-                    self.hit = Some((covering.pos, hit.get_object_field(mv.block_hit_result_dir).unwrap().read_dir()));
-                    break;
-                }
-            }
-        }
+        (self.dst, self.hit) = trace_beam(&level, self.src, self.dir, |pos| {
+            self.chunks.insert(block_to_chunk(pos));
+            self.blocks.push(pos);
+            dim.track_block(pos, id)
+        });
         for &pos in self.chunks.difference(&old_chunks) {
             let c_state = dim.chunks.entry(pos).or_default();
             c_state.beams.insert(id);
@@ -241,6 +235,38 @@ impl BeamState {
     }
 }
 
+pub fn trace_beam<'a>(
+    level: &impl JRef<'a>,
+    src: Point3<i32>,
+    dir: UnitVector3<f32>,
+    mut visit: impl FnMut(Point3<i32>),
+) -> (Point3<f64>, Option<(Point3<i32>, u8)>) {
+    let jni = level.jni();
+    let mv = &objs().mv;
+    let mut covering = CoveringBlocks::new(src, Vector3::from_element(0.5), dir.cast());
+    let j_src = write_vec3d(jni, covering.pos.cast::<f64>().map(|x| x + 0.5));
+    let chunk_source = level.level_get_chunk_source();
+    loop {
+        covering.step();
+        visit(covering.pos);
+        let chunk = block_to_chunk(covering.pos);
+        let Some(Some(chunk)) = (!level.is_outside_build_height(covering.pos.y)).then(|| chunk_source.loaded_chunk_at(chunk)) else {
+            return (covering.pos.cast::<f64>() + covering.frac, None);
+        };
+        let pos = write_block_pos(jni, covering.pos);
+        let state = chunk.block_state_at(&pos);
+        let args = [chunk.raw, pos.raw, mv.collision_ctx_empty.raw];
+        let shape = state.call_object_method(mv.block_state_get_visual_shape, &args).unwrap().unwrap();
+        let j_dst = write_vec3d(jni, (covering.pos.cast::<f64>() + covering.frac + *covering.dir * 2.).cast());
+        if let Some(hit) = shape.call_object_method(mv.voxel_shape_clip, &[j_src.raw, j_dst.raw, pos.raw]).unwrap() {
+            if !hit.get_bool_field(mv.block_hit_result_miss) {
+                let dst = hit.get_object_field(mv.block_hit_result_pos).unwrap().read_vec3d().cast();
+                return (dst, Some((covering.pos, hit.get_object_field(mv.block_hit_result_dir).unwrap().read_dir())));
+            }
+        }
+    }
+}
+
 pub fn on_chunk_watch(player: &impl JRef<'static>, level: &impl JRef<'static>, pos: Point2<i32>) {
     let lk = objs().mtx.lock(level.jni()).unwrap();
     let mut srv = lk.server_state.borrow_mut();
@@ -351,6 +377,7 @@ pub fn add_beam(lk: &GlobalMtx, level: &impl JRef<'static>, tier: u8, src: Point
         dst: <_>::default(),
         hit: None,
         active: false,
+        idle: false,
         dirty: true,
     });
     beam.recompute(level.jni(), &mut srv.players, dim, id);
@@ -369,29 +396,128 @@ pub fn set_beam_dir(lk: &GlobalMtx, jni: &'static JNI, id: NonZeroUsize, dir: Un
     beam.broadcast_set_beam(jni, id)
 }
 
+const HUM_SOUND: &CStr = c"block.beacon.ambient";
+const CRACKLE_SOUND: &CStr = c"block.fire.ambient";
+const CRACKLE_TICKS: usize = 30;
+
+impl ClientState {
+    pub fn set_beam(&mut self, jni: &'static JNI, id: NonZeroUsize, beam: ClientBeam) {
+        let (was_active, moved, dst) = match self.beams.remove(&id) {
+            Some((old, mut dst)) => {
+                dst.set(beam.dst);
+                (old.active, old.dst != beam.dst, dst)
+            }
+            None => (false, false, Smoothed::new(beam.dst)),
+        };
+        if !beam.active {
+            self.stop_hum(jni, id)
+        } else {
+            if let hash_map::Entry::Vacant(entry) = self.hums.entry(id) {
+                let pitch = 0.5 + beam.tier as f32 * 0.1;
+                let hum = new_block_sound(jni, HUM_SOUND, beam.src.cast::<f64>().map(|x| x + 0.5), 0.5, pitch, true);
+                play_sound(&hum);
+                entry.insert(hum.new_global_ref().unwrap());
+            }
+            if !was_active || moved {
+                play_sound(&new_block_sound(jni, CRACKLE_SOUND, beam.dst, 1., 1., false))
+            }
+        }
+        self.beams.insert(id, (beam, dst));
+    }
+
+    pub fn tick(&mut self, jni: &'static JNI) {
+        self.ticks += 1;
+        for (id, (beam, _)) in &self.beams {
+            // Staggered so that beams turned on together don't crackle in unison.
+            if beam.active && (self.ticks + id.get()) % CRACKLE_TICKS == 0 {
+                play_sound(&new_block_sound(jni, CRACKLE_SOUND, beam.dst, 1., 1., false))
+            }
+        }
+    }
+
+    pub fn del_beam(&mut self, jni: &JNI, id: NonZeroUsize) {
+        self.beams.remove(&id);
+        self.stop_hum(jni, id)
+    }
+
+    pub fn clear(&mut self, jni: &JNI) {
+        self.beams.clear();
+        for (_, hum) in self.hums.drain() {
+            stop_sound(&hum.replace_jni(jni))
+        }
+    }
+
+    fn stop_hum(&mut self, jni: &JNI, id: NonZeroUsize) {
+        if let Some(hum) = self.hums.remove(&id) {
+            stop_sound(&hum.replace_jni(jni))
+        }
+    }
+}
+
 impl ClientBeam {
-    pub fn render<'a>(&self, tiers: &[Tier], vb: &impl JRef<'a>, pose: &impl JRef<'a>, camera_pos: Point3<f64>, tick: i32, sub_tick: f32) {
+    pub fn render<'a>(
+        &self,
+        tiers: &[Tier],
+        style: &BeamStyle,
+        vb: &impl JRef<'a>,
+        pose: &impl JRef<'a>,
+        camera_pos: Point3<f64>,
+        dst: Point3<f64>,
+        tick: i32,
+        sub_tick: f32,
+    ) {
         // TODO: frustum culling
-        let mvc = objs().mv.client.uref();
+        let true = ((self.active || self.idle) && style.opacity > 0.) else { return };
         let src = (self.src.cast::<f64>().map(|x| x + 0.5) - camera_pos).cast::<f32>();
-        let dst = (self.dst - camera_pos).cast::<f32>();
-        let dir = (dst - src).normalize();
-        let mut b = Vector3::zeros();
-        b[dir.abs().argmin().0] = 1.;
-        let radius = if self.active { 0.2 } else { 0.1 };
-        let mut n = b.cross(&dir) * radius;
-        let mut b = n.cross(&dir);
+        let dst = (dst - camera_pos).cast::<f32>();
+        let (mut n, mut b) = tube_axes(&(dst - src).normalize(), if self.active { 0.2 } else { 0.1 } * style.width);
         if self.active {
-            const PERIOD: i32 = 10;
-            let (s, c) = libm::sincosf(((tick % PERIOD) as f32 + sub_tick) * (TAU / PERIOD as f32));
+            const PERIOD: f64 = 10.;
+            let phase = (tick as f64 + sub_tick as f64) * style.anim_speed as f64 / PERIOD;
+            let (s, c) = libm::sincosf((phase - libm::floor(phase)) as f32 * TAU);
             (n, b) = (c * n - s * b, s * n + c * b)
         }
-        let pts = [src + n, dst + n, src + b, dst + b, src - n, dst - n, src - b, dst - b];
-        let color = tiers[self.tier as usize].color;
-        for i in [0, 1, 3, 2, 2, 3, 5, 4, 4, 5, 7, 6, 6, 7, 1, 0] {
-            let p = pts[i];
-            vb.call_object_method(mvc.vertex_consumer_pos, &[pose.raw(), f_raw(p.x), f_raw(p.y), f_raw(p.z)]).unwrap();
-            vb.call_object_method(mvc.vertex_consumer_color, &[f_raw(color.x), f_raw(color.y), f_raw(color.z), f_raw(1.)]).unwrap();
-        }
+        render_tube(vb, pose, src, dst, n, b, tiers[self.tier as usize].color.push(style.opacity))
+    }
+}
+
+fn tube_axes(dir: &Vector3<f32>, radius: f32) -> (Vector3<f32>, Vector3<f32>) {
+    let mut b = Vector3::zeros();
+    b[dir.abs().argmin().0] = 1.;
+    let n = b.cross(dir) * radius;
+    (n, n.cross(dir))
+}
+
+fn render_tube<'a>(
+    vb: &impl JRef<'a>,
+    pose: &impl JRef<'a>,
+    src: Vector3<f32>,
+    dst: Vector3<f32>,
+    n: Vector3<f32>,
+    b: Vector3<f32>,
+    color: Vector4<f32>,
+) {
+    let mvc = objs().mv.client.uref();
+    let pts = [src + n, dst + n, src + b, dst + b, src - n, dst - n, src - b, dst - b];
+    for i in [0, 1, 3, 2, 2, 3, 5, 4, 4, 5, 7, 6, 6, 7, 1, 0] {
+        let p = pts[i];
+        vb.call_object_method(mvc.vertex_consumer_pos, &[pose.raw(), f_raw(p.x), f_raw(p.y), f_raw(p.z)]).unwrap();
+        vb.call_object_method(mvc.vertex_consumer_color, &[f_raw(color.x), f_raw(color.y), f_raw(color.z), f_raw(color.w)]).unwrap();
+    }
+}
+
+pub fn render_beam_stub<'a>(vb: &impl JRef<'a>, pose: &impl JRef<'a>, src: Vector3<f32>, dst: Vector3<f32>, color: Vector3<f32>) {
+    let (n, b) = tube_axes(&(dst - src).normalize(), 0.04);
+    render_tube(vb, pose, src, dst, n, b, color.push(0.8))
+}
+
+pub fn render_dashed_beam<'a>(vb: &impl JRef<'a>, pose: &impl JRef<'a>, src: Vector3<f32>, dst: Vector3<f32>, color: Vector3<f32>) {
+    const DASH_LEN: f32 = 0.25;
+    let (dir, len) = Unit::new_and_get(dst - src);
+    let (n, b) = tube_axes(&dir, 0.03);
+    let mut t = 0.;
+    while t < len {
+        render_tube(vb, pose, src + *dir * t, src + *dir * (t + DASH_LEN).min(len), n, b, color.push(0.6));
+        t += DASH_LEN * 2.
     }
 }
@@ -0,0 +1,158 @@
+use crate::{
+    global::{warn, Tier},
+    jvm::*,
+    mapping_base::*,
+    objs,
+    util::{
+        gui::KEY_B,
+        mapping::{ForgeMV, MV},
+    },
+};
+use alloc::{format, string::String, sync::Arc};
+use core::{ffi::CStr, str};
+use hashbrown::HashMap;
+use nalgebra::vector;
+
+pub struct ClientConfig {
+    spec: GlobalRef<'static>,
+    beam_opacity: GlobalRef<'static>,
+    beam_width: GlobalRef<'static>,
+    beam_anim_speed: GlobalRef<'static>,
+    tier_colors: GlobalRef<'static>,
+    pub hide_beams_key: GlobalRef<'static>,
+}
+
+#[derive(Clone, Copy)]
+pub struct BeamStyle {
+    pub opacity: f32,
+    pub width: f32,
+    pub anim_speed: f32,
+}
+
+impl Default for BeamStyle {
+    fn default() -> Self { Self { opacity: 1., width: 1., anim_speed: 1. } }
+}
+
+pub struct ServerConfig {
+    spec: GlobalRef<'static>,
+    base_amps: GlobalRef<'static>,
+    buffer_packets: GlobalRef<'static>,
+    idle_drain: GlobalRef<'static>,
+}
+
+#[derive(Clone, Copy)]
+pub struct Balance {
+    pub base_amps: i64,
+    pub buffer_packets: i64,
+    pub idle_drain: i64,
+}
+
+impl Default for Balance {
+    fn default() -> Self { Self { base_amps: 1, buffer_packets: 2, idle_drain: 1 } }
+}
+
+impl ServerConfig {
+    pub fn new(jni: &'static JNI, fmv: &ForgeMV) -> Self {
+        let utf = |x: &CStr| jni.new_utf(x).unwrap();
+        let builder = fmv.config_builder.with_jni(jni).new_object(fmv.config_builder_init, &[]).unwrap();
+        let comment = |x: &CStr| builder.call_object_method(fmv.config_builder_comment, &[utf(x).raw]).unwrap();
+        let define_long = |path: &CStr, default: i64, min: i64, max: i64| {
+            let args = [utf(path).raw, default as _, min as _, max as _];
+            builder.call_object_method(fmv.config_builder_define_long, &args).unwrap().unwrap().new_global_ref().unwrap()
+        };
+        builder.call_object_method(fmv.config_builder_push, &[utf(c"emitters").raw]).unwrap();
+        comment(c"Amps an emitter takes and sends without upgrades. Each amperage upgrade adds one.");
+        let base_amps = define_long(c"baseAmperage", 1, 1, 64);
+        comment(c"Packets of the emitter's voltage that its buffer holds per amp.");
+        let buffer_packets = define_long(c"bufferPackets", 2, 1, 64);
+        comment(c"EU drained per tick while an emitter holds energy but has nowhere to send it. An efficiency upgrade stops it.");
+        let idle_drain = define_long(c"idleDrain", 1, 0, i32::MAX as _);
+        builder.call_object_method(fmv.config_builder_pop, &[]).unwrap();
+        let spec = builder.call_object_method(fmv.config_builder_build, &[]).unwrap().unwrap();
+        fmv.mod_container.with_jni(jni).call_void_method(fmv.mod_container_reg_config, &[fmv.config_type_server.raw, spec.raw]).unwrap();
+        Self { spec: spec.new_global_ref().unwrap(), base_amps, buffer_packets, idle_drain }
+    }
+
+    pub fn balance(&self, jni: &JNI) -> Balance {
+        let fmv = &objs().fmv;
+        let true = self.spec.with_jni(jni).call_bool_method(fmv.config_spec_is_loaded, &[]).unwrap() else { return <_>::default() };
+        let get = |x: &GlobalRef| x.with_jni(jni).call_long_method(fmv.config_long_value_get, &[]).unwrap();
+        Balance { base_amps: get(&self.base_amps), buffer_packets: get(&self.buffer_packets), idle_drain: get(&self.idle_drain) }
+    }
+}
+
+impl ClientConfig {
+    pub fn new(jni: &'static JNI, fmv: &ForgeMV, mv: &MV) -> Self {
+        let mvc = mv.client.uref();
+        let utf = |x: &CStr| jni.new_utf(x).unwrap();
+        let builder = fmv.config_builder.with_jni(jni).new_object(fmv.config_builder_init, &[]).unwrap();
+        let comment = |x: &CStr| builder.call_object_method(fmv.config_builder_comment, &[utf(x).raw]).unwrap();
+        let define_double = |path: &CStr, default: f64, min: f64, max: f64| {
+            let args = [utf(path).raw, d_raw(default), d_raw(min), d_raw(max)];
+            builder.call_object_method(fmv.config_builder_define_double, &args).unwrap().unwrap().new_global_ref().unwrap()
+        };
+        builder.call_object_method(fmv.config_builder_push, &[utf(c"beams").raw]).unwrap();
+        comment(c"Opacity of beams, from invisible (0) to fully opaque (1).");
+        let beam_opacity = define_double(c"opacity", 1., 0., 1.);
+        comment(c"Multiplier on the thickness of beams.");
+        let beam_width = define_double(c"width", 1., 0.1, 4.);
+        comment(c"Multiplier on the spinning speed of active beams. 0 stops the animation.");
+        let beam_anim_speed = define_double(c"animationSpeed", 1., 0., 4.);
+        comment(c"Overrides of GregTech's tier colours for beams, e.g. \"lv=#DCDCDC, mv=#FF6400\".");
+        let tier_colors = builder.call_object_method(fmv.config_builder_define, &[utf(c"tierColors").raw, utf(c"").raw]).unwrap().unwrap();
+        builder.call_object_method(fmv.config_builder_pop, &[]).unwrap();
+        let spec = builder.call_object_method(fmv.config_builder_build, &[]).unwrap().unwrap();
+        fmv.mod_container.with_jni(jni).call_void_method(fmv.mod_container_reg_config, &[fmv.config_type_client.raw, spec.raw]).unwrap();
+        let key_args = [utf(c"key.greg_emitters.hide_beams").raw, KEY_B as _, utf(c"key.categories.greg_emitters").raw];
+        let hide_beams_key = mvc.key_mapping.with_jni(jni).new_object(mvc.key_mapping_init, &key_args).unwrap();
+        Self {
+            spec: spec.new_global_ref().unwrap(),
+            beam_opacity,
+            beam_width,
+            beam_anim_speed,
+            tier_colors: tier_colors.new_global_ref().unwrap(),
+            hide_beams_key: hide_beams_key.new_global_ref().unwrap(),
+        }
+    }
+
+    fn is_loaded(&self, jni: &JNI) -> bool { self.spec.with_jni(jni).call_bool_method(objs().fmv.config_spec_is_loaded, &[]).unwrap() }
+
+    pub fn beam_style(&self, jni: &JNI) -> BeamStyle {
+        let true = self.is_loaded(jni) else { return <_>::default() };
+        let get = |x: &GlobalRef| x.with_jni(jni).call_double_method(objs().fmv.config_double_value_get, &[]).unwrap() as f32;
+        BeamStyle { opacity: get(&self.beam_opacity), width: get(&self.beam_width), anim_speed: get(&self.beam_anim_speed) }
+    }
+
+    pub fn poll_hide_beams(&self, jni: &JNI) -> bool {
+        let mut toggled = false;
+        while self.hide_beams_key.with_jni(jni).call_bool_method(objs().mv.client.uref().key_mapping_consume_click, &[]).unwrap() {
+            toggled = !toggled
+        }
+        toggled
+    }
+
+    pub fn apply_tier_colors(&self, jni: &JNI, tiers: &mut [Tier], lookup: &HashMap<Arc<str>, u8>) {
+        if tiers.is_empty() || !self.is_loaded(jni) {
+            return;
+        }
+        for tier in &mut *tiers {
+            tier.color = tier.gt_color
+        }
+        let text = self.tier_colors.with_jni(jni).call_object_method(objs().fmv.config_value_get, &[]).unwrap().unwrap();
+        // Modified UTF-8 encodes supplementary characters as surrogates, which are only ever in invalid entries anyway.
+        let text = text.utf_chars().unwrap();
+        for entry in String::from_utf8_lossy(&text).split(',').map(str::trim).filter(|x| !x.is_empty()) {
+            let parsed: Option<_> = try {
+                let (name, color) = entry.split_once('=')?;
+                let &tier = lookup.get(&*name.trim().to_lowercase())?;
+                let color = color.trim().trim_start_matches('#');
+                (color.len() == 6).then_some(())?;
+                (tier, u32::from_str_radix(color, 16).ok()?)
+            };
+            match parsed {
+                Some((tier, color)) => tiers[tier as usize].color = vector![color >> 16, (color >> 8) & 255, color & 255].map(|x| x as f32 / 255.),
+                None => warn(jni, &cs(format!("Invalid tier colour override: {entry}"))),
+            }
+        }
+    }
+}
@@ -1,37 +1,49 @@
 use crate::{
     asm::*,
-    beams::{add_beam, del_beam},
+    beams::{add_beam, del_beam, render_beam_stub, set_beam_dir, trace_beam},
+    config::Balance,
     emitter_gui::{EmitterMenu, EmitterMenuType},
+    emitter_items::{set_pipe_conn, write_item_data},
     global::{GlobalMtx, GlobalObjs, Tier},
     jvm::*,
     mapping_base::*,
     objs,
     registry::{register, EMITTER_ID},
     ti,
+    upgrades::{ItemHandler, Upgrade, UPGRADE_SLOTS},
     util::{
         cleaner::Cleanable,
-        client::SolidRenderer,
+        client::{write_pose, Interpolate, Smoothed, SolidRenderer, FULL_BRIGHT, NO_OVERLAY},
         geometry::{lerp, new_voxel_shape, write_block_pos, write_dir, GeomExt, DIR_ATTS},
+        gui::GUIExt,
+        nbt::NBTExt,
         strict_deserialize,
-        tile::{Tile, TileExt, TileSupplier},
-        ClassBuilder, ThinWrapper,
+        tile::{Tile, TileExt, TileSupplier, Unversioned, Versioned},
+        write_uuid, ClassBuilder, ThinWrapper, UtilExt,
     },
 };
-use alloc::{format, sync::Arc, vec::Vec};
-use anyhow::Result;
+use alloc::{format, string::String, sync::Arc, vec::Vec};
+use anyhow::{anyhow, bail, ensure, Result};
+use bstr::BStr;
 use core::{
     any::Any,
     array,
     cell::{Cell, OnceCell, RefCell},
-    f32::consts::{PI, TAU},
+    f32::consts::{FRAC_PI_2, PI, TAU},
+    ffi::CStr,
+    mem::replace,
     num::NonZeroUsize,
 };
 use macros::dyn_abi;
-use nalgebra::{point, vector, Affine3, Point, Scale3, Translation3, Unit, UnitQuaternion, UnitVector3};
+use nalgebra::{point, vector, Affine3, Point, Point3, Scale3, Translation3, Unit, UnitQuaternion, UnitVector3};
+use num_traits::Euclid;
 use serde::{Deserialize, Serialize};
 use simba::scalar::SupersetOf;
 
 const RADIUS: f32 = 0.25;
+const LIGHT_LINGER: u8 = 40;
+// Block update flag that only sends the new state to clients, as neighbours don't care about light levels.
+const UPDATE_CLIENTS: i32 = 2;
 
 pub struct EmitterBlocks {
     block: ThinWrapper<Block>,
@@ -42,57 +54,374 @@ pub struct EmitterBlocks {
     pub cap_provider: GlobalRef<'static>,
 }
 
-#[derive(Default, Clone, Serialize, Deserialize)]
+const SAVE_VERSION: i32 = 0;
+
+#[derive(Default, Clone)]
 pub struct EmitterData {
     pub dir: u8,
     pub zenith: f32,
     pub azimuth: f32,
     pub disable_transfer: bool,
     pub energy: i64,
+    pub upgrades: [Option<Upgrade>; UPGRADE_SLOTS],
+    pub owner: Option<Owner>,
+    pub access: Access,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Owner {
+    pub id: u128,
+    pub name: String,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Access {
+    #[default]
+    Public,
+    Team,
+    Owner,
+}
+
+impl Access {
+    pub const ALL: [Self; 3] = [Self::Public, Self::Team, Self::Owner];
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Team => "team",
+            Self::Owner => "owner",
+        }
+    }
+
+    pub fn from_id(id: &[u8]) -> Option<Self> { Self::ALL.into_iter().find(|x| x.id().as_bytes() == id) }
+    pub fn next(self) -> Self { Self::ALL[(self as usize + 1) % Self::ALL.len()] }
+    pub fn key(self) -> &'static CStr {
+        match self {
+            Self::Public => c"greg_emitters.access.public",
+            Self::Team => c"greg_emitters.access.team",
+            Self::Owner => c"greg_emitters.access.owner",
+        }
+    }
+}
+
+impl Owner {
+    pub fn id_of<'a>(player: &impl JRef<'a>) -> u128 { player.call_object_method(objs().mv.entity_get_uuid, &[]).unwrap().unwrap().read_uuid() }
+
+    pub fn of<'a>(player: &impl JRef<'a>) -> Self {
+        let profile = player.get_object_field(objs().mv.player_profile).unwrap();
+        Self { id: Self::id_of(player), name: profile_name(&profile) }
+    }
+
+    fn current_name<'a>(&self, player: &impl JRef<'a>) -> Option<String> {
+        let mv = &objs().mv;
+        let server = player.call_object_method(mv.entity_get_server, &[]).unwrap()?;
+        let cache = server.call_object_method(mv.mc_server_get_profile_cache, &[]).unwrap()?;
+        let profile = cache.call_object_method(mv.profile_cache_get, &[write_uuid(player.jni(), self.id).raw]).unwrap().unwrap();
+        Some(profile_name(&profile.call_object_method(mv.optional_or_else, &[0]).unwrap()?))
+    }
+}
+
+fn profile_name<'a>(profile: &impl JRef<'a>) -> String {
+    let name = profile.call_object_method(objs().mv.game_profile_get_name, &[]).unwrap().unwrap();
+    String::from_utf8_lossy(&name.utf_chars().unwrap()).into_owned()
+}
+
+pub const MAX_NAME_LEN: usize = 50;
+
+pub fn clean_name(name: &str) -> Option<String> {
+    let name: String = name.chars().filter(|x| !x.is_control()).take(MAX_NAME_LEN).collect();
+    Some(String::from(name.trim())).filter(|x| !x.is_empty())
+}
+
+fn is_op<'a>(player: &impl JRef<'a>) -> bool { player.call_bool_method(objs().mv.player_has_permissions, &[2]).unwrap() }
+
+#[derive(Serialize, Deserialize)]
+pub struct EmitterDataV0 {
+    dir: u8,
+    zenith: f32,
+    azimuth: f32,
+    disable_transfer: bool,
+    energy: i64,
 }
 
+// Facing isn't synced, as clients have the block state.
 #[derive(Serialize, Deserialize)]
 struct SyncData {
-    pub dir: u8,
     pub zenith: f32,
     pub azimuth: f32,
     pub disable_transfer: bool,
+    pub access: Access,
+    pub name: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
-enum SaveData {
-    V0(EmitterData),
+impl Versioned for EmitterDataV0 {
+    const VERSION: u32 = 0;
+    type Prev = Unversioned;
+    fn upgrade(prev: Unversioned) -> Self { match prev {} }
+}
+
+impl From<EmitterDataV0> for EmitterData {
+    fn from(EmitterDataV0 { dir, zenith, azimuth, disable_transfer, energy }: EmitterDataV0) -> Self {
+        Self { dir, zenith, azimuth, disable_transfer, energy, ..<_>::default() }
+    }
+}
+
+pub const REACH_RINGS: usize = 6;
+pub const REACH_SECTORS: usize = 24;
+const REACH_TTL: i64 = 100;
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Reach {
+    Nothing,
+    Inert,
+    Receiver,
+}
+
+#[derive(Clone, Copy)]
+struct Attitude {
+    zenith: f32,
+    azimuth: f32,
+}
+
+impl Interpolate for Attitude {
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        let d_azimuth = (to.azimuth - self.azimuth + PI).rem_euclid(&TAU) - PI;
+        Self { zenith: lerp(self.zenith, to.zenith, t), azimuth: self.azimuth + d_azimuth * t }
+    }
 }
 
 #[derive(Default)]
 struct EnergyStats {
     eu_accepted: i64,
     snap_eu_accepted: i64,
+    eu_emitted: i64,
+    snap_eu_emitted: i64,
     time: u8,
 }
 
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmitterStatus {
+    pub energy: i64,
+    pub capacity: i64,
+    pub eu_in: i64,
+    pub eu_out: i64,
+    pub target: Option<TargetInfo>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetInfo {
+    pub pos: Point3<i32>,
+    pub side: u8,
+    pub desc_id: String,
+    pub name: Option<String>,
+    pub receiver: bool,
+}
+
 pub struct Emitter {
     tier: u8,
     energy_cap: RefCell<Option<GlobalRef<'static>>>,
+    pub upgrade_handler: RefCell<Option<GlobalRef<'static>>>,
     pub data: RefCell<EmitterData>,
     pub beam_id: Cell<Option<NonZeroUsize>>,
     stats: RefCell<EnergyStats>,
+    shown_att: RefCell<Option<Smoothed<Attitude>>>,
+    reach: RefCell<Option<(i64, Vec<Reach>)>>,
+    lit_ticks: Cell<u8>,
 }
 
 impl Cleanable for Emitter {
-    fn free(self: Arc<Self>, jni: &JNI) { Arc::into_inner(self).unwrap().energy_cap.into_inner().map(|x| x.replace_jni(jni)); }
+    fn free(self: Arc<Self>, jni: &JNI) {
+        let this = Arc::into_inner(self).unwrap();
+        this.energy_cap.into_inner().map(|x| x.replace_jni(jni));
+        this.upgrade_handler.into_inner().map(|x| x.replace_jni(jni));
+    }
 }
 
 impl Emitter {
     fn volts(&self, tiers: &[Tier]) -> i64 { tiers[self.tier as usize].volt }
-    fn eu_capacity(&self, tiers: &[Tier]) -> i64 { self.volts(tiers) * 2 }
+    fn amps(&self, lk: &GlobalMtx) -> i64 { self.data.borrow().amps(&lk.balance.get()) }
+    pub fn eu_capacity(&self, lk: &GlobalMtx) -> i64 { self.data.borrow().eu_capacity(self.volts(&lk.tiers.borrow()), &lk.balance.get()) }
+    fn idle_drain(&self, lk: &GlobalMtx) -> i64 { self.data.borrow().idle_drain(&lk.balance.get()) }
+
+    pub fn reach<'a>(&self, lk: &GlobalMtx, level: &impl JRef<'a>, src: Point3<i32>) -> Vec<Reach> {
+        let time = level.level_game_time();
+        if let Some((scanned, reach)) = &*self.reach.borrow() {
+            if time - scanned < REACH_TTL {
+                return reach.clone();
+            }
+        }
+        let reach = scan_reach(lk, level, src, &self.data.borrow());
+        *self.reach.borrow_mut() = Some((time, reach.clone()));
+        reach
+    }
+
+    fn check_access<'a>(&self, tile: &impl JRef<'a>, player: &impl JRef<'a>) -> bool {
+        let id = Owner::id_of(player);
+        if !self.can_access(player, id) {
+            let msg = player.jni().new_utf(c"greg_emitters.access.denied").unwrap().translatable();
+            player.call_void_method(objs().mv.player_display_client_message, &[msg.raw, true as _]).unwrap();
+            return false;
+        }
+        let mut data = self.data.borrow_mut();
+        if let Some(owner) = data.owner.as_mut().filter(|x| x.id == id) {
+            let player = Owner::of(player);
+            if owner.name != player.name {
+                *owner = player;
+                tile.tile_mark_for_save()
+            }
+        }
+        true
+    }
+
+    fn beam_light(&self) -> i32 { (6 + self.tier as i32).min(15) }
+
+    pub fn set_upgrade<'a>(&self, lk: &GlobalMtx, tile: &impl JRef<'a>, slot: usize, kind: Option<Upgrade>) {
+        match self.data.borrow_mut().upgrades.get_mut(slot) {
+            Some(x) => *x = kind,
+            None => return,
+        }
+        let capacity = self.eu_capacity(lk);
+        let mut data = self.data.borrow_mut();
+        data.energy = data.energy.min(capacity);
+        tile.tile_mark_for_save()
+    }
+
+    fn shown_data(&self, time: f32) -> EmitterData {
+        let data = self.data.borrow().clone();
+        let Attitude { zenith, azimuth } =
+            self.shown_att.borrow().as_ref().map_or(Attitude { zenith: data.zenith, azimuth: data.azimuth }, |x| x.get(time));
+        EmitterData { zenith, azimuth, ..data }
+    }
+
+    pub fn render_preview<'a>(&self, lk: &GlobalMtx, source: &impl JRef<'a>, tf: Affine3<f32>, time: f32) {
+        let mvc = objs().mv.client.uref();
+        self.render(lk, SolidRenderer::new(lk, source, FULL_BRIGHT, NO_OVERLAY), tf, time);
+        source.call_void_method(mvc.buffer_source_end_batch, &[lk.sheets_solid.get().unwrap().raw]).unwrap();
+        let dir = self.shown_data(time).compute_dir();
+        let center = vector![0.5, 0.5, 0.5];
+        let color = lk.tiers.borrow()[self.tier as usize].color;
+        let vb = source.call_object_method(mvc.multi_buffer_source_get_buffer, &[mvc.render_type_lightning.raw]).unwrap().unwrap();
+        render_beam_stub(&vb, &write_pose(source.jni(), &tf), center + *dir * RADIUS, center + *dir * 1.1, color);
+        source.call_void_method(mvc.buffer_source_end_batch, &[mvc.render_type_lightning.raw]).unwrap()
+    }
+
+    pub fn can_access<'a>(&self, player: &impl JRef<'a>, player_id: u128) -> bool {
+        let mv = &objs().mv;
+        let data = self.data.borrow();
+        let Some(owner) = &data.owner else { return true };
+        if data.access == Access::Public || owner.id == player_id || is_op(player) {
+            return true;
+        }
+        let Access::Team = data.access else { return false };
+        let Some(team) = player.call_object_method(mv.entity_get_team, &[]).unwrap() else { return false };
+        let name = owner.current_name(player).unwrap_or_else(|| owner.name.clone());
+        let members = team.call_object_method(mv.player_team_get_players, &[]).unwrap().unwrap();
+        members.call_bool_method(mv.collection_contains, &[player.jni().new_utf(&cs(name)).unwrap().raw]).unwrap()
+    }
+
+    pub fn can_manage<'a>(&self, player: &impl JRef<'a>, player_id: u128) -> bool {
+        let data = self.data.borrow();
+        data.owner.as_ref().is_none_or(|x| x.id == player_id) || is_op(player)
+    }
+
+    pub fn status<'a>(&self, lk: &GlobalMtx, level: &impl JRef<'a>) -> EmitterStatus {
+        let hit: Option<_> = try { lk.server_state.borrow().beams.get(&self.beam_id.get()?)?.hit? };
+        let target = hit.map(|(pos, side)| {
+            let jni = level.jni();
+            let j_pos = write_block_pos(jni, pos);
+            let block = level.block_state_at(&j_pos).block_state_get_block();
+            let desc_id = block.call_object_method(objs().mv.block_get_desc_id, &[]).unwrap().unwrap();
+            let desc_id = String::from_utf8_lossy(&desc_id.utf_chars().unwrap()).into_owned();
+            let name: Option<_> = try {
+                let tile = level.tile_at(&j_pos).filter(|x| x.is_instance_of(objs().tile_defs.tile.cls.cls.raw))?;
+                let emitter = lk.try_read_tile::<Emitter>(tile.borrow())?;
+                let name = emitter.data.borrow().name.clone();
+                name?
+            };
+            TargetInfo { pos, side, desc_id, name, receiver: find_receiver(lk, level, &j_pos, &write_dir(jni, side)).is_some() }
+        });
+        let stats = self.stats.borrow();
+        EmitterStatus {
+            energy: self.data.borrow().energy,
+            capacity: self.eu_capacity(lk),
+            eu_in: stats.snap_eu_accepted,
+            eu_out: stats.snap_eu_emitted,
+            target,
+        }
+    }
 }
 
 impl EmitterData {
+    pub fn upgrade_count(&self, kind: Upgrade) -> usize { self.upgrades.iter().filter(|&&x| x == Some(kind)).count() }
+    pub fn amps(&self, balance: &Balance) -> i64 { balance.base_amps + self.upgrade_count(Upgrade::Amperage) as i64 }
+    pub fn eu_capacity(&self, volts: i64, balance: &Balance) -> i64 { volts * balance.buffer_packets * self.amps(balance) }
+    pub fn idle_drain(&self, balance: &Balance) -> i64 {
+        if self.upgrade_count(Upgrade::Efficiency) > 0 {
+            0
+        } else {
+            balance.idle_drain
+        }
+    }
     pub fn compute_dir(&self) -> UnitVector3<f32> {
         let att = DIR_ATTS[self.dir as usize] * DIR_ATTS[0] * UnitQuaternion::from_euler_angles(self.zenith, self.azimuth, 0.);
         att * Unit::new_unchecked(vector![0., 1., 0.])
     }
+
+    pub fn rebase(&mut self, dir: u8) {
+        if dir == self.dir {
+            return;
+        }
+        let local = (DIR_ATTS[dir as usize] * DIR_ATTS[0]).inverse() * self.compute_dir();
+        self.dir = dir;
+        self.zenith = libm::acosf(local.y.clamp(-1., 1.)).min(FRAC_PI_2);
+        self.azimuth = libm::atan2f(local.x, local.z).rem_euclid(&TAU)
+    }
+
+    pub fn has_settings(&self) -> bool {
+        self.zenith != 0. || self.azimuth != 0. || self.disable_transfer || self.energy != 0 || self.upgrades.iter().any(Option::is_some)
+    }
+
+    pub fn write_settings(&self, tag: BorrowedRef) {
+        tag.compound_put_float(c"zenith", self.zenith);
+        tag.compound_put_float(c"azimuth", self.azimuth);
+        tag.compound_put_bool(c"disable_transfer", self.disable_transfer);
+        tag.compound_put_long(c"energy", self.energy);
+        // Empty slots are kept as "" so that the others stay in place.
+        tag.compound_put_string_list(c"upgrades", self.upgrades.iter().map(|x| x.map_or("", |x| x.id())));
+    }
+
+    // Tags that are missing keep their current values. Nothing changes on error.
+    pub fn read_settings(&mut self, tag: BorrowedRef) -> Result<()> {
+        let zenith = tag.compound_get_float(c"zenith").unwrap_or(self.zenith);
+        let azimuth = tag.compound_get_float(c"azimuth").unwrap_or(self.azimuth);
+        ensure!(zenith.is_finite() && azimuth.is_finite(), "bad attitude {zenith}, {azimuth}");
+        let energy = tag.compound_get_long(c"energy").unwrap_or(self.energy);
+        ensure!(energy >= 0, "bad energy {energy}");
+        let ids = tag.compound_get_string_list(c"upgrades");
+        let mut upgrades = self.upgrades;
+        if !ids.is_empty() {
+            ensure!(ids.len() <= UPGRADE_SLOTS, "too many upgrades");
+            upgrades = <_>::default();
+            for (slot, id) in upgrades.iter_mut().zip(ids) {
+                *slot = match &*id {
+                    b"" => None,
+                    id => Some(Upgrade::from_id(id).ok_or_else(|| anyhow!("unknown upgrade {}", BStr::new(id)))?),
+                }
+            }
+        }
+        self.zenith = zenith.clamp(0., FRAC_PI_2);
+        self.azimuth = azimuth.rem_euclid(&TAU);
+        self.disable_transfer = tag.compound_get_bool(c"disable_transfer").unwrap_or(self.disable_transfer);
+        self.energy = energy;
+        self.upgrades = upgrades;
+        Ok(())
+    }
+}
+
+pub fn read_dir_tag(tag: BorrowedRef) -> Result<Option<u8>> {
+    let Some(dir) = tag.compound_get_int(c"dir") else { return Ok(None) };
+    ensure!((0..6).contains(&dir), "bad dir {dir}");
+    Ok(Some(dir as _))
 }
 
 struct EnergyContainer {
@@ -116,7 +445,7 @@ impl EmitterBlocks {
         let GlobalObjs { av, cn, mn, mv, fcn, fmn, gcn, gmn, tile_defs, gui_defs, .. } = objs();
         let energy_container = ClassBuilder::new_2(jni, c"java/lang/Object")
             .interfaces([&*gcn.energy_container.slash])
-            .insns(&gmn.get_input_amps, [av.new_ldc_insn(jni, av.jv.wrap_long(jni, 1).unwrap().raw).unwrap(), av.new_insn(jni, OP_LRETURN).unwrap()])
+            .native_2(&gmn.get_input_amps, get_input_amps_dyn())
             .native_2(&gmn.can_input_eu_from_side, can_input_eu_from_side_dyn())
             .native_2(&gmn.accept_eu, accept_eu_dyn())
             .native_2(&gmn.change_eu, change_eu_dyn())
@@ -133,7 +462,7 @@ impl EmitterBlocks {
 
         // Blocks
         let block = ClassBuilder::new_2(jni, &cn.base_tile_block.slash)
-            .interfaces([&*cn.tile_ticker.slash])
+            .interfaces([&*cn.tile_ticker.slash, &*cn.simple_waterlogged.slash])
             .native_2(&mn.tile_block_new_tile, new_tile_dyn())
             .native_2(&mn.tile_block_get_ticker, get_ticker_dyn())
             .native_2(&mn.tile_ticker_tick, on_tick_dyn())
@@ -142,12 +471,26 @@ impl EmitterBlocks {
             .native_2(&mn.block_beh_get_drops, get_drops_dyn())
             .native_2(&mn.block_beh_on_place, on_place_dyn())
             .native_2(&mn.block_beh_use, on_use_dyn())
+            .native_2(&mn.block_beh_use_item_on, on_use_item_dyn())
+            .native_2(&mn.block_create_state_def, create_state_def_dyn())
+            .native_2(&mn.block_get_state_for_placement, get_state_for_placement_dyn())
+            .native_2(&mn.block_beh_rotate, rotate_dyn())
+            .native_2(&mn.block_beh_mirror, mirror_dyn())
+            .native_2(&mn.block_beh_get_fluid_state, get_fluid_state_dyn())
+            .native_2(&mn.block_beh_update_shape, update_shape_dyn())
             .define_thin()
             .wrap::<Block>();
         let mut props = mv.block_beh_props.with_jni(jni).call_static_object_method(mv.block_beh_props_of, &[]).unwrap().unwrap();
         props = props.call_object_method(mv.block_beh_props_strength, &[f_raw(0.25), f_raw(1E6)]).unwrap().unwrap();
         props = props.call_object_method(mv.block_beh_props_dyn_shape, &[]).unwrap().unwrap();
         props = props.call_object_method(mv.block_beh_props_sound, &[mv.sound_type_metal.raw]).unwrap().unwrap();
+        let light_fn = ClassBuilder::new_2(jni, c"java/lang/Object")
+            .interfaces([c"java/util/function/ToIntFunction"])
+            .native_1(c"applyAsInt", c"(Ljava/lang/Object;)I", light_emission_dyn())
+            .define_empty()
+            .alloc_object()
+            .unwrap();
+        props = props.call_object_method(mv.block_beh_props_light_level, &[light_fn.raw]).unwrap().unwrap();
         let tiers = lk.tiers.borrow();
         let n_emitter_tiers = tiers.iter().filter(|x| x.has_emitter).count();
         let mut blocks = block.cls.cls.new_object_array(n_emitter_tiers as _, 0).unwrap();
@@ -182,104 +525,172 @@ impl EmitterBlocks {
 
 impl Tile for Emitter {
     fn any(&self) -> &dyn Any { self }
-    fn encode_save(&self) -> Vec<u8> { postcard::to_allocvec(&SaveData::V0(self.data.borrow().clone())).unwrap() }
+    fn encode_save(&self, tag: BorrowedRef) {
+        let data = self.data.borrow();
+        tag.compound_put_int(c"version", SAVE_VERSION);
+        data.write_settings(tag);
+        // Not kept in dropped items, as whoever places one owns it.
+        if let Some(owner) = &data.owner {
+            tag.compound_put_uuid(c"owner", owner.id);
+            tag.compound_put_string(c"owner_name", &owner.name)
+        }
+        tag.compound_put_string(c"access", data.access.id());
+        if let Some(name) = &data.name {
+            tag.compound_put_string(c"name", name)
+        }
+    }
+
     fn encode_sync(&self) -> Vec<u8> {
-        let EmitterData { dir, zenith, azimuth, disable_transfer, .. } = *self.data.borrow();
-        postcard::to_allocvec(&SyncData { dir, zenith, azimuth, disable_transfer }).unwrap()
+        let data = self.data.borrow();
+        let EmitterData { zenith, azimuth, disable_transfer, access, .. } = *data;
+        postcard::to_allocvec(&SyncData { zenith, azimuth, disable_transfer, access, name: data.name.clone() }).unwrap()
     }
 
-    fn decode_save(&self, bytes: &[u8]) -> Result<()> {
-        let mut data = self.data.borrow_mut();
-        match strict_deserialize::<SaveData>(bytes) {
-            Ok(SaveData::V0(x)) => Ok(*data = x),
-            Err(e) => Err(e),
+    fn decode_save(&self, tag: BorrowedRef) -> Result<()> {
+        match tag.compound_get_int(c"version").unwrap_or(SAVE_VERSION) {
+            SAVE_VERSION => (),
+            version => bail!("unknown save data version {version}"),
+        }
+        let mut data = self.data.borrow().clone();
+        data.read_settings(tag)?;
+        if let Some(dir) = read_dir_tag(tag)? {
+            data.dir = dir
+        }
+        if let Some(id) = tag.compound_get_uuid(c"owner") {
+            let name = tag.compound_get_string(c"owner_name").unwrap_or_default();
+            data.owner = Some(Owner { id, name: String::from_utf8_lossy(&name).into_owned() })
         }
+        if let Some(id) = tag.compound_get_string(c"access") {
+            data.access = Access::from_id(&id).ok_or_else(|| anyhow!("unknown access {}", BStr::new(&id)))?
+        }
+        if let Some(name) = tag.compound_get_string(c"name") {
+            data.name = clean_name(&String::from_utf8_lossy(&name))
+        }
+        Ok(*self.data.borrow_mut() = data)
     }
 
+    fn decode_legacy_save(&self, bytes: &[u8]) -> Result<()> { Ok(*self.data.borrow_mut() = EmitterDataV0::decode_versioned(bytes)?.into()) }
+
     fn decode_sync(&self, bytes: &[u8]) -> Result<()> {
         let mut data = self.data.borrow_mut();
         match strict_deserialize::<SyncData>(bytes) {
-            Ok(SyncData { dir, zenith, azimuth, disable_transfer }) => Ok(*data = EmitterData { dir, zenith, azimuth, disable_transfer, ..*data }),
+            Ok(SyncData { zenith, azimuth, disable_transfer, access, name }) => {
+                match &mut *self.shown_att.borrow_mut() {
+                    Some(shown) => shown.set(Attitude { zenith, azimuth }),
+                    shown => *shown = Some(Smoothed::new(Attitude { zenith, azimuth })),
+                }
+                Ok((data.zenith, data.azimuth, data.disable_transfer, data.access, data.name) = (zenith, azimuth, disable_transfer, access, name))
+            }
             Err(e) => Err(e),
         }
     }
 
     fn set_removed(&self, jni: &JNI, lk: &GlobalMtx) {
         self.energy_cap.borrow_mut().take().map(|x| x.replace_jni(jni));
+        self.upgrade_handler.borrow_mut().take().map(|x| x.replace_jni(jni));
         if let Some(beam_id) = self.beam_id.take() {
             del_beam(jni, lk, beam_id)
         }
     }
 
-    fn render(&self, lk: &GlobalMtx, mut sr: SolidRenderer, mut tf: Affine3<f32>) {
-        let EmitterData { dir, azimuth, zenith, .. } = *self.data.borrow();
-        tf *= Translation3::new(0.5, 0.5, 0.5) * DIR_ATTS[dir as usize] * DIR_ATTS[0];
-        tf *= UnitQuaternion::from_euler_angles(0., azimuth, 0.);
-        // Legs
-        const LEG_LEN: f32 = 0.3;
-        const LEG_DIA: f32 = 0.05;
-        const LEG_POS: f32 = RADIUS * 0.6;
-        let greg_wire = lk.wire_sprite.get().unwrap();
-        let leg_side = greg_wire.sub(0., 0., LEG_DIA, LEG_LEN);
-        let leg_bot = greg_wire.sub(0., 0., LEG_DIA, LEG_DIA);
-        for x in [-LEG_POS, LEG_POS] {
-            let tf = tf * Translation3::new(x, 0., 0.);
-            sr.square(&leg_bot, &(tf * Translation3::new(0., -0.5, 0.) * DIR_ATTS[0] * Affine3::from_subset(&Scale3::new(LEG_DIA, LEG_DIA, 1.))));
-            let mut face = Translation3::new(0., LEG_LEN * 0.5 - 0.5, LEG_DIA * 0.5) * Affine3::from_subset(&Scale3::new(LEG_DIA, LEG_LEN, 1.));
-            for _ in 0..4 {
-                sr.square(&leg_side, &(tf * face));
-                face = DIR_ATTS[4] * face;
-            }
+    fn set_block_state(&self, lk: &GlobalMtx, state: BorrowedRef<'static, '_>) {
+        let mut data = self.data.borrow_mut();
+        let dir = state.block_state_get_facing();
+        if dir == data.dir {
+            return;
         }
-        // Cylinder (r, h, v)
-        const CONTOUR: [(f32, f32, f32); 4] = [(1., 0., 0.), (1., 1., 1.), (0.9, 1., 0.8), (0.6, 0.8, 0.6)];
-        const N_SEGS: usize = 8;
-        tf *= UnitQuaternion::from_euler_angles(zenith, 0., 0.);
-        let base = vector![RADIUS, libm::tanf(PI / N_SEGS as f32) * RADIUS];
-        let bot_y = LEG_LEN - 0.5;
-        let bot_q = tf * point![0., bot_y, 0.];
-        let bot_m = tf * vector![0., -1., 0.];
-        let top_y = RADIUS;
-        let top_p = point![0., lerp(bot_y, top_y, 0.7), 0.];
-        let top_q = tf * top_p;
-        let mut p0 = CONTOUR.map(|(r, h, _)| point![base.x * r, lerp(bot_y, top_y, h), base.y * r]);
-        let mut q0 = p0.map(|p| tf * p);
-        let mut n0: [_; 4] = array::from_fn(|i| (p0.get(i + 1).unwrap_or(&top_p) - p0[i]).cross(&vector![-base.y, 0., base.x]).normalize());
-        let mut m0 = n0.map(|n| tf * n);
-        let rot = UnitQuaternion::from_euler_angles(0., TAU / N_SEGS as f32, 0.);
-        let spr = lk.tiers.borrow()[self.tier as usize].emitter_sprite.uref().sub(0.4, 0.2, 0.6, 0.4);
-        for _ in 0..N_SEGS / 2 {
-            let (p1, n1) = (p0.map(|p| rot * p), n0.map(|n| rot * n));
-            let (p2, n2) = (p1.map(|p| rot * p), n1.map(|n| rot * n));
-            let (q1, m1) = (p1.map(|p| tf * p), n1.map(|n| tf * n));
-            let (q2, m2) = (p2.map(|p| tf * p), n2.map(|n| tf * n));
-            // Side Contour
-            for i in 0..CONTOUR.len() - 1 {
-                let v0 = spr.lerp_v(CONTOUR[i].2);
-                let v1 = spr.lerp_v(CONTOUR[i + 1].2);
-                sr.vertex(q0[i], m0[i], spr.uv0.x, v0);
-                sr.vertex(q1[i], m1[i], spr.uv1.x, v0);
-                sr.vertex(q1[i + 1], m1[i], spr.uv1.x, v1);
-                sr.vertex(q0[i + 1], m0[i], spr.uv0.x, v1);
-                sr.vertex(q1[i], m1[i], spr.uv1.x, v0);
-                sr.vertex(q2[i], m2[i], spr.uv0.x, v0);
-                sr.vertex(q2[i + 1], m2[i], spr.uv0.x, v1);
-                sr.vertex(q1[i + 1], m1[i], spr.uv1.x, v1);
-            }
-            // Bottom Cap
-            let v = spr.lerp_v(CONTOUR[0].2);
-            sr.vertex(bot_q, bot_m, spr.uv1.x, spr.uv1.y);
-            sr.vertex(q2[0], bot_m, spr.uv0.x, v);
-            sr.vertex(q1[0], bot_m, spr.uv1.x, v);
-            sr.vertex(q0[0], bot_m, spr.uv0.x, v);
-            // Top Cap
-            let v = spr.lerp_v(CONTOUR.last().unwrap().2);
-            sr.vertex(top_q, *m1.last().unwrap(), spr.uv1.x, spr.uv1.y);
-            sr.vertex(*q0.last().unwrap(), *m0.last().unwrap(), spr.uv0.x, v);
-            sr.vertex(*q1.last().unwrap(), *m1.last().unwrap(), spr.uv1.x, v);
-            sr.vertex(*q2.last().unwrap(), *m2.last().unwrap(), spr.uv1.x, v);
-            (p0 = p2, q0 = q2, n0 = n2, m0 = m2);
+        data.dir = dir;
+        if let Some(beam_id) = self.beam_id.get() {
+            set_beam_dir(lk, state.jni, beam_id, data.compute_dir())
+        }
+        *self.reach.borrow_mut() = None
+    }
+
+    fn on_load(&self, _lk: &GlobalMtx, tile: BorrowedRef<'static, '_>) {
+        let Some(level) = tile.tile_level().filter(|x| !x.level_is_client()) else { return };
+        let pos = tile.tile_pos();
+        let state = level.block_state_at(&pos);
+        let facing = state.block_state_get_facing();
+        // Set back so that the block state change below turns the beam too.
+        let dir = replace(&mut self.data.borrow_mut().dir, facing);
+        if facing != dir {
+            level.call_bool_method(objs().mv.level_set_block_and_update, &[pos.raw, state.block_state_with_facing(dir).raw]).unwrap();
+        }
+    }
+
+    fn render(&self, lk: &GlobalMtx, sr: SolidRenderer, tf: Affine3<f32>, time: f32) {
+        let EmitterData { dir, zenith, azimuth, .. } = self.shown_data(time);
+        render_emitter(lk, sr, tf, self.tier, dir, zenith, azimuth)
+    }
+}
+
+pub fn render_emitter(lk: &GlobalMtx, mut sr: SolidRenderer, mut tf: Affine3<f32>, tier: u8, dir: u8, zenith: f32, azimuth: f32) {
+    tf *= Translation3::new(0.5, 0.5, 0.5) * DIR_ATTS[dir as usize] * DIR_ATTS[0];
+    tf *= UnitQuaternion::from_euler_angles(0., azimuth, 0.);
+    // Legs
+    const LEG_LEN: f32 = 0.3;
+    const LEG_DIA: f32 = 0.05;
+    const LEG_POS: f32 = RADIUS * 0.6;
+    let greg_wire = lk.wire_sprite.get().unwrap();
+    let leg_side = greg_wire.sub(0., 0., LEG_DIA, LEG_LEN);
+    let leg_bot = greg_wire.sub(0., 0., LEG_DIA, LEG_DIA);
+    for x in [-LEG_POS, LEG_POS] {
+        let tf = tf * Translation3::new(x, 0., 0.);
+        sr.square(&leg_bot, &(tf * Translation3::new(0., -0.5, 0.) * DIR_ATTS[0] * Affine3::from_subset(&Scale3::new(LEG_DIA, LEG_DIA, 1.))));
+        let mut face = Translation3::new(0., LEG_LEN * 0.5 - 0.5, LEG_DIA * 0.5) * Affine3::from_subset(&Scale3::new(LEG_DIA, LEG_LEN, 1.));
+        for _ in 0..4 {
+            sr.square(&leg_side, &(tf * face));
+            face = DIR_ATTS[4] * face;
+        }
+    }
+    // Cylinder (r, h, v)
+    const CONTOUR: [(f32, f32, f32); 4] = [(1., 0., 0.), (1., 1., 1.), (0.9, 1., 0.8), (0.6, 0.8, 0.6)];
+    const N_SEGS: usize = 8;
+    tf *= UnitQuaternion::from_euler_angles(zenith, 0., 0.);
+    let base = vector![RADIUS, libm::tanf(PI / N_SEGS as f32) * RADIUS];
+    let bot_y = LEG_LEN - 0.5;
+    let bot_q = tf * point![0., bot_y, 0.];
+    let bot_m = tf * vector![0., -1., 0.];
+    let top_y = RADIUS;
+    let top_p = point![0., lerp(bot_y, top_y, 0.7), 0.];
+    let top_q = tf * top_p;
+    let mut p0 = CONTOUR.map(|(r, h, _)| point![base.x * r, lerp(bot_y, top_y, h), base.y * r]);
+    let mut q0 = p0.map(|p| tf * p);
+    let mut n0: [_; 4] = array::from_fn(|i| (p0.get(i + 1).unwrap_or(&top_p) - p0[i]).cross(&vector![-base.y, 0., base.x]).normalize());
+    let mut m0 = n0.map(|n| tf * n);
+    let rot = UnitQuaternion::from_euler_angles(0., TAU / N_SEGS as f32, 0.);
+    let spr = lk.tiers.borrow()[tier as usize].emitter_sprite.uref().sub(0.4, 0.2, 0.6, 0.4);
+    for _ in 0..N_SEGS / 2 {
+        let (p1, n1) = (p0.map(|p| rot * p), n0.map(|n| rot * n));
+        let (p2, n2) = (p1.map(|p| rot * p), n1.map(|n| rot * n));
+        let (q1, m1) = (p1.map(|p| tf * p), n1.map(|n| tf * n));
+        let (q2, m2) = (p2.map(|p| tf * p), n2.map(|n| tf * n));
+        // Side Contour
+        for i in 0..CONTOUR.len() - 1 {
+            let v0 = spr.lerp_v(CONTOUR[i].2);
+            let v1 = spr.lerp_v(CONTOUR[i + 1].2);
+            sr.vertex(q0[i], m0[i], spr.uv0.x, v0);
+            sr.vertex(q1[i], m1[i], spr.uv1.x, v0);
+            sr.vertex(q1[i + 1], m1[i], spr.uv1.x, v1);
+            sr.vertex(q0[i + 1], m0[i], spr.uv0.x, v1);
+            sr.vertex(q1[i], m1[i], spr.uv1.x, v0);
+            sr.vertex(q2[i], m2[i], spr.uv0.x, v0);
+            sr.vertex(q2[i + 1], m2[i], spr.uv0.x, v1);
+            sr.vertex(q1[i + 1], m1[i], spr.uv1.x, v1);
         }
+        // Bottom Cap
+        let v = spr.lerp_v(CONTOUR[0].2);
+        sr.vertex(bot_q, bot_m, spr.uv1.x, spr.uv1.y);
+        sr.vertex(q2[0], bot_m, spr.uv0.x, v);
+        sr.vertex(q1[0], bot_m, spr.uv1.x, v);
+        sr.vertex(q0[0], bot_m, spr.uv0.x, v);
+        // Top Cap
+        let v = spr.lerp_v(CONTOUR.last().unwrap().2);
+        sr.vertex(top_q, *m1.last().unwrap(), spr.uv1.x, spr.uv1.y);
+        sr.vertex(*q0.last().unwrap(), *m0.last().unwrap(), spr.uv0.x, v);
+        sr.vertex(*q1.last().unwrap(), *m1.last().unwrap(), spr.uv1.x, v);
+        sr.vertex(*q2.last().unwrap(), *m2.last().unwrap(), spr.uv1.x, v);
+        (p0 = p2, q0 = q2, n0 = n2, m0 = m2);
     }
 }
 
@@ -291,28 +702,57 @@ impl TileSupplier for EmitterSupplier {
         // Block and tile can mismatch when loading corrupted save.
         let true = block.is_instance_of(defs.block.cls.cls.raw) else { return None };
         let energy_container = Arc::new(EnergyContainer { tile: OnceCell::new() });
+        let upgrade_handler = Arc::new(ItemHandler { tile: OnceCell::new() });
         let emitter = Arc::new(Emitter {
             tier: defs.block.read(&lk, block.borrow()).tier,
             energy_cap: Some(defs.energy_container.new_obj(pos.jni, energy_container.clone()).new_global_ref().unwrap()).into(),
-            data: <_>::default(),
+            upgrade_handler: Some(lk.upgrades.get().unwrap().new_handler(pos.jni, upgrade_handler.clone()).new_global_ref().unwrap()).into(),
+            data: EmitterData { dir: state.block_state_get_facing(), ..<_>::default() }.into(),
             beam_id: None.into(),
             stats: <_>::default(),
+            shown_att: None.into(),
+            reach: None.into(),
+            lit_ticks: 0.into(),
         });
         let tile = objs().tile_defs.new_tile(pos.jni, defs.tile_type.raw, pos.raw, state.raw, emitter);
         energy_container.tile.set(tile.new_weak_global_ref().unwrap()).ok().unwrap();
+        upgrade_handler.tile.set(tile.new_weak_global_ref().unwrap()).ok().unwrap();
         Some(tile)
     }
 }
 
+fn scan_reach<'a>(lk: &GlobalMtx, level: &impl JRef<'a>, src: Point3<i32>, data: &EmitterData) -> Vec<Reach> {
+    let jni = level.jni();
+    let mut data = data.clone();
+    let samples = (0..REACH_RINGS * REACH_SECTORS).map(|i| {
+        data.zenith = ((i / REACH_SECTORS) as f32 + 0.5) * (FRAC_PI_2 / REACH_RINGS as f32);
+        data.azimuth = ((i % REACH_SECTORS) as f32 + 0.5) * (TAU / REACH_SECTORS as f32);
+        let Some((pos, side)) = trace_beam(level, src, data.compute_dir(), |_| ()).1 else { return Reach::Nothing };
+        match find_receiver(lk, level, &write_block_pos(jni, pos), &write_dir(jni, side)) {
+            Some(_) => Reach::Receiver,
+            None => Reach::Inert,
+        }
+    });
+    samples.collect()
+}
+
+fn find_receiver<'a>(lk: &GlobalMtx, level: &impl JRef<'a>, pos: &impl JRef<'a>, dir: &impl JRef<'a>) -> Option<LocalRef<'a>> {
+    let gmv = lk.gmv.get().unwrap();
+    let cap = level.call_object_method(objs().fmv.level_get_cap, &[gmv.energy_container_cap.raw, pos.raw(), dir.raw()]).unwrap()?;
+    cap.call_bool_method(gmv.can_input_eu_from_side, &[dir.raw()]).unwrap().then_some(cap)
+}
+
 #[dyn_abi]
-fn on_tick(jni: &'static JNI, _this: usize, level: usize, pos: usize, _state: usize, tile: usize) {
-    let GlobalObjs { fmv, mtx, .. } = objs();
-    let lk = mtx.lock(jni).unwrap();
+fn on_tick(jni: &'static JNI, _this: usize, level: usize, pos: usize, state: usize, tile: usize) {
+    let lk = objs().mtx.lock(jni).unwrap();
     let tile = BorrowedRef::new(jni, &tile);
     let level = BorrowedRef::new(jni, &level);
     let emitter = lk.read_tile::<Emitter>(tile);
-    let volts = emitter.volts(&*lk.tiers.borrow()).min(emitter.data.borrow().energy);
-    let mut active = false;
+    let energy = emitter.data.borrow().energy;
+    let volts = emitter.volts(&*lk.tiers.borrow()).min(energy);
+    // The last packet may be short of full voltage, but never split across amps.
+    let amps = (energy / volts.max(1)).clamp(1, emitter.amps(&lk));
+    let mut sent = 0;
     if let Some(beam_id) = emitter.beam_id.get() {
         let mut srv_guard = lk.server_state.borrow_mut();
         let srv = &mut *srv_guard;
@@ -329,67 +769,157 @@ fn on_tick(jni: &'static JNI, _this: usize, level: usize, pos: usize, _state: us
             let false = emitter.data.borrow().disable_transfer else { break 'fail };
             let Some((pos, dir)) = hit else { break 'fail };
             let true = volts > 0 else { break 'fail };
-            let pos = write_block_pos(jni, pos);
             let dir = write_dir(jni, dir);
-            let gmv = lk.gmv.get().unwrap();
-            let args = [gmv.energy_container_cap.raw, pos.raw, dir.raw];
-            let Some(cap) = level.call_object_method(fmv.level_get_cap, &args).unwrap() else { break 'fail };
-            let true = cap.call_bool_method(gmv.can_input_eu_from_side, &[dir.raw]).unwrap() else { break 'fail };
-            active = cap.call_long_method(gmv.accept_eu, &[dir.raw, volts as _, 1]).unwrap() > 0
+            let Some(cap) = find_receiver(&lk, &level, &write_block_pos(jni, pos), &dir) else { break 'fail };
+            sent = cap.call_long_method(lk.gmv.get().unwrap().accept_eu, &[dir.raw, volts as _, amps as _]).unwrap().clamp(0, amps)
         }
         srv_guard = lk.server_state.borrow_mut();
         // accept_eu may have called something that deleted the beam.
         let Some(beam) = srv_guard.beams.get_mut(&beam_id) else { return };
-        if beam.active != active {
-            beam.active = active;
+        if beam.active != (sent > 0) {
+            beam.active = sent > 0;
+            should_broadcast = true
+        }
+        if beam.idle != (emitter.idle_drain(&lk) > 0) {
+            beam.idle = !beam.idle;
             should_broadcast = true
         }
         if should_broadcast {
             beam.broadcast_set_beam(jni, beam_id)
         }
     }
+    let active = sent > 0;
+    let idle_drain = emitter.idle_drain(&lk);
     let mut data = emitter.data.borrow_mut();
     if active || data.energy > 0 {
-        data.energy -= if active { volts } else { 1 }; // TODO: configurable quiescent draw
-        tile.tile_mark_for_save();
+        let drain = if active { volts * sent } else { idle_drain };
+        if drain > 0 {
+            data.energy -= drain;
+            tile.tile_mark_for_save()
+        }
         if emitter.beam_id.get().is_none() {
             emitter.beam_id.set(Some(add_beam(&lk, &level, emitter.tier, BorrowedRef::new(jni, &pos).read_vec3i(), data.compute_dir())))
         }
     } else if let Some(beam_id) = emitter.beam_id.take() {
         del_beam(jni, &lk, beam_id)
     }
+    drop(data);
+    let state = BorrowedRef::new(jni, &state);
+    emitter.lit_ticks.set(if active { LIGHT_LINGER } else { emitter.lit_ticks.get().saturating_sub(1) });
+    let light = if emitter.lit_ticks.get() > 0 { emitter.beam_light() } else { 0 };
+    if state.block_state_get_level() != light {
+        let state = state.block_state_with_level(light);
+        level.call_bool_method(objs().mv.level_set_block, &[pos, state.raw, UPDATE_CLIENTS as _]).unwrap();
+    }
     let mut stats = emitter.stats.borrow_mut();
+    stats.eu_emitted += volts * sent;
     stats.time += 1;
     if stats.time == 20 {
         stats.time = 0;
         stats.snap_eu_accepted = stats.eu_accepted;
-        stats.eu_accepted = 0
+        stats.eu_accepted = 0;
+        stats.snap_eu_emitted = stats.eu_emitted;
+        stats.eu_emitted = 0
+    }
+}
+
+fn drop_stacks<'a>(lk: &GlobalMtx, jni: &'a JNI, tier: u8, emitter: Option<&Emitter>) -> Vec<LocalRef<'a>> {
+    let mv = &objs().mv;
+    let item = lk.tiers.borrow()[tier as usize].emitter_item.get().unwrap().raw;
+    let stack = mv.item_stack.with_jni(jni).new_object(mv.item_stack_init, &[item, 1]).unwrap();
+    if let Some(emitter) = emitter {
+        write_item_data(&stack, &emitter.data.borrow())
     }
+    Vec::from([stack])
 }
 
 #[dyn_abi]
-fn get_drops(jni: &JNI, this: usize, _state: usize, _loot_builder: usize) -> usize {
+fn get_drops(jni: &JNI, this: usize, _state: usize, loot_builder: usize) -> usize {
     let GlobalObjs { mtx, av, mv, .. } = objs();
     let lk = mtx.lock(jni).unwrap();
-    let tiers = lk.tiers.borrow();
     let tier = lk.emitter_blocks.get().unwrap().block.read(&lk, BorrowedRef::new(jni, &this)).tier;
-    let item = tiers[tier as usize].emitter_item.get().unwrap();
-    let stack = mv.item_stack.with_jni(jni).new_object(mv.item_stack_init, &[item.raw, 1]).unwrap();
-    mv.item_stack.with_jni(jni).new_object_array(1, stack.raw).unwrap().array_as_list(&av.jv).unwrap().into_raw()
+    let loot_builder = BorrowedRef::new(jni, &loot_builder);
+    let tile = loot_builder.call_object_method(mv.loot_builder_get_optional, &[mv.loot_ctx_params_tile.raw]).unwrap();
+    let stacks = drop_stacks(&lk, jni, tier, tile.as_ref().and_then(|x| lk.try_read_tile::<Emitter>(x.borrow())));
+    let mut array = mv.item_stack.with_jni(jni).new_object_array(stacks.len() as _, 0).unwrap();
+    for (i, stack) in stacks.iter().enumerate() {
+        array.set_object_elem(i as _, stack.raw).unwrap()
+    }
+    array.array_as_list(&av.jv).unwrap().into_raw()
 }
 
 #[dyn_abi]
-fn get_shape(jni: &JNI, _this: usize, _state: usize, level: usize, pos: usize, _collision_ctx: usize) -> usize {
-    let GlobalObjs { mv, mtx, .. } = objs();
-    let lk = mtx.lock(jni).unwrap();
-    let defs = lk.emitter_blocks.get().unwrap();
-    // Rethrow is needed for lithium's SingleBlockBlockView.
-    match BorrowedRef::new(jni, &level).call_object_method(mv.block_getter_get_tile, &[pos]) {
-        Ok(Some(tile)) => defs.shapes[lk.read_tile::<Emitter>(tile.borrow()).data.borrow().dir as usize].raw,
-        Ok(None) => defs.shapes[0].raw,
-        Err(JVMError::Throwable(e)) => e.throw().map(|_| 0).unwrap(),
-        Err(e) => panic!("{e}"),
+fn get_shape(jni: &JNI, _this: usize, state: usize, _level: usize, _pos: usize, _collision_ctx: usize) -> usize {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let result = lk.emitter_blocks.get().unwrap().shapes[BorrowedRef::new(jni, &state).block_state_get_facing() as usize].raw;
+    result
+}
+
+#[dyn_abi]
+fn create_state_def(jni: &JNI, _this: usize, builder: usize) {
+    let mv = &objs().mv;
+    let mut props = mv.prop.with_jni(jni).new_object_array(3, 0).unwrap();
+    for (i, prop) in [&mv.block_state_props_facing, &mv.block_state_props_waterlogged, &mv.block_state_props_level].into_iter().enumerate() {
+        props.set_object_elem(i as _, prop.raw).unwrap()
     }
+    BorrowedRef::new(jni, &builder).call_object_method(mv.state_def_builder_add, &[props.raw]).unwrap();
+}
+
+#[dyn_abi]
+fn get_state_for_placement(jni: &JNI, this: usize, ctx: usize) -> usize {
+    let mv = &objs().mv;
+    let ctx = BorrowedRef::new(jni, &ctx);
+    let dir = ctx.call_object_method(mv.use_on_ctx_get_clicked_face, &[]).unwrap().unwrap().read_dir();
+    let level = ctx.call_object_method(mv.use_on_ctx_get_level, &[]).unwrap().unwrap();
+    let pos = ctx.call_object_method(mv.use_on_ctx_get_clicked_pos, &[]).unwrap().unwrap();
+    let fluid = level.call_object_method(mv.block_getter_get_fluid_state, &[pos.raw]).unwrap().unwrap();
+    let fluid = fluid.call_object_method(mv.fluid_state_get_type, &[]).unwrap().unwrap();
+    let state = BorrowedRef::new(jni, &this).call_object_method(mv.block_default_state, &[]).unwrap().unwrap();
+    state.block_state_with_facing(dir).block_state_with_waterlogged(fluid.is_same_object(mv.fluids_water.raw)).into_raw()
+}
+
+#[dyn_abi]
+fn get_fluid_state(jni: &JNI, this: usize, state: usize) -> usize {
+    let mv = &objs().mv;
+    if BorrowedRef::new(jni, &state).block_state_is_waterlogged() {
+        return mv.fluids_water_source.raw;
+    }
+    BorrowedRef::new(jni, &this).call_nonvirtual_object_method(mv.block.raw, mv.block_beh_get_fluid_state, &[state]).unwrap().unwrap().into_raw()
+}
+
+#[dyn_abi]
+fn update_shape(jni: &JNI, this: usize, state: usize, dir: usize, other: usize, level: usize, pos: usize, other_pos: usize) -> usize {
+    let mv = &objs().mv;
+    if BorrowedRef::new(jni, &state).block_state_is_waterlogged() {
+        let water = mv.fluids_water.with_jni(jni);
+        let delay = water.call_int_method(mv.fluid_get_tick_delay, &[level]).unwrap();
+        BorrowedRef::new(jni, &level).call_void_method(mv.level_access_schedule_fluid_tick, &[pos, water.raw, delay as _]).unwrap()
+    }
+    let args = [state, dir, other, level, pos, other_pos];
+    BorrowedRef::new(jni, &this).call_nonvirtual_object_method(mv.block.raw, mv.block_beh_update_shape, &args).unwrap().unwrap().into_raw()
+}
+
+// Set from on_tick, as light levels must come from the block state.
+#[dyn_abi]
+fn light_emission(jni: &JNI, _this: usize, state: usize) -> i32 { BorrowedRef::new(jni, &state).block_state_get_level() }
+
+fn rotate_state<'a>(state: BorrowedRef<'a, '_>, rotation: &impl JRef<'a>) -> LocalRef<'a> {
+    let dir = write_dir(state.jni, state.block_state_get_facing());
+    let dir = rotation.call_object_method(objs().mv.rotation_rotate, &[dir.raw]).unwrap().unwrap().read_dir();
+    state.block_state_with_facing(dir)
+}
+
+#[dyn_abi]
+fn rotate(jni: &JNI, _this: usize, state: usize, rotation: usize) -> usize {
+    rotate_state(BorrowedRef::new(jni, &state), &BorrowedRef::new(jni, &rotation)).into_raw()
+}
+
+#[dyn_abi]
+fn mirror(jni: &JNI, _this: usize, state: usize, mirror: usize) -> usize {
+    let state = BorrowedRef::new(jni, &state);
+    let dir = write_dir(jni, state.block_state_get_facing());
+    let rotation = BorrowedRef::new(jni, &mirror).call_object_method(objs().mv.mirror_get_rotation, &[dir.raw]).unwrap().unwrap();
+    rotate_state(state, &rotation).into_raw()
 }
 
 #[dyn_abi]
@@ -421,15 +951,81 @@ fn on_use(jni: &'static JNI, _block: usize, _state: usize, level: usize, pos: us
     let true = player.is_instance_of(mv.server_player.raw) else { return mv.interaction_result_pass.raw };
     let lk = mtx.lock(jni).unwrap();
     let tile = level.tile_at(&pos).unwrap();
-    let tiers = lk.tiers.borrow();
-    let item = tiers[lk.read_tile::<Emitter>(tile.borrow()).tier as usize].emitter_item.get().unwrap().with_jni(jni);
-    let title = item.call_nonvirtual_object_method(mv.item.raw, mv.item_get_desc_id, &[]).unwrap().unwrap();
+    let emitter = lk.read_tile::<Emitter>(tile.borrow());
+    if !emitter.check_access(&tile, &player) {
+        return mv.interaction_result_consume.raw;
+    }
+    let title = match &emitter.data.borrow().name {
+        Some(name) => jni.new_utf(&cs(name.as_str())).unwrap().literal(),
+        None => {
+            let tiers = lk.tiers.borrow();
+            let item = tiers[emitter.tier as usize].emitter_item.get().unwrap().with_jni(jni);
+            item.call_nonvirtual_object_method(mv.item.raw, mv.item_get_desc_id, &[]).unwrap().unwrap().translatable()
+        }
+    };
     let data = postcard::to_allocvec(&pos.read_vec3i()).unwrap();
     let menu = EmitterMenu::new_server(tile.new_weak_global_ref().unwrap());
     gui_defs.open_menu(&player, &EmitterMenuType, Arc::new(menu), &title, data);
     mv.interaction_result_consume.raw
 }
 
+#[dyn_abi]
+fn on_use_item(jni: &'static JNI, _: usize, stack: usize, state: usize, level: usize, pos: usize, player: usize, _hand: usize, hit: usize) -> usize {
+    let GlobalObjs { mv, mtx, .. } = objs();
+    let lk = mtx.lock(jni).unwrap();
+    let gmv = lk.gmv.get().unwrap();
+    let is_tool = |tool: &GlobalRef| gmv.tool_helper.with_jni(jni).call_static_bool_method(gmv.tool_helper_is, &[stack, tool.raw]).unwrap();
+    let Some(tool) = [&gmv.tool_type_wrench, &gmv.tool_type_screwdriver].into_iter().find(|x| is_tool(x)) else {
+        return mv.item_interaction_result_pass.raw;
+    };
+    let level = BorrowedRef::new(jni, &level);
+    let player = BorrowedRef::new(jni, &player);
+    let pos = BorrowedRef::new(jni, &pos);
+    let false = level.level_is_client() else { return mv.item_interaction_result_success.raw };
+    let true = player.is_instance_of(mv.server_player.raw) else { return mv.item_interaction_result_pass.raw };
+    let tile = level.tile_at(&pos).unwrap();
+    let emitter = lk.read_tile::<Emitter>(tile.borrow());
+    if !emitter.check_access(&tile, &player) {
+        return mv.item_interaction_result_success.raw;
+    }
+    let changed = || {
+        tile.tile_mark_for_save();
+        level.level_mark_for_broadcast(&pos)
+    };
+    if tool.raw == gmv.tool_type_screwdriver.raw {
+        let mut data = emitter.data.borrow_mut();
+        data.disable_transfer = !data.disable_transfer;
+        drop(data);
+        changed()
+    } else if player.call_bool_method(mv.entity_is_shift_key_down, &[]).unwrap() {
+        let stacks = drop_stacks(&lk, jni, emitter.tier, Some(emitter));
+        level.call_bool_method(mv.level_remove_block, &[pos.raw, 0]).unwrap();
+        let inv = player.call_object_method(mv.player_get_inventory, &[]).unwrap().unwrap();
+        for stack in stacks {
+            inv.call_void_method(mv.inventory_place_back, &[stack.raw]).unwrap()
+        }
+    } else {
+        let hit_dir = BorrowedRef::new(jni, &hit).get_object_field(mv.block_hit_result_dir).unwrap().read_dir();
+        let mut data = emitter.data.borrow_mut();
+        let old_dir = data.dir;
+        data.rebase(if hit_dir == old_dir { old_dir ^ 1 } else { hit_dir });
+        if let Some(beam_id) = emitter.beam_id.get() {
+            set_beam_dir(&lk, jni, beam_id, data.compute_dir())
+        }
+        let new_dir = data.dir;
+        drop(data);
+        let state = BorrowedRef::new(jni, &state).block_state_with_facing(new_dir);
+        level.call_bool_method(mv.level_set_block_and_update, &[pos.raw, state.raw]).unwrap();
+        let src = pos.read_vec3i();
+        set_pipe_conn(&lk, &level, src, &tile, old_dir, false);
+        set_pipe_conn(&lk, &level, src, &tile, new_dir, true);
+        changed()
+    }
+    gmv.tool_helper.with_jni(jni).call_static_void_method(gmv.tool_helper_play_sound, &[tool.raw, player.raw]).unwrap();
+    gmv.tool_helper.with_jni(jni).call_static_void_method(gmv.tool_helper_damage, &[stack, player.raw, 1]).unwrap();
+    mv.item_interaction_result_success.raw
+}
+
 #[dyn_abi]
 fn get_cap(jni: &JNI, _this: usize, tile: usize, _side: usize) -> usize {
     objs().mtx.lock(jni).unwrap().read_tile::<Emitter>(BorrowedRef::new(jni, &tile)).energy_cap.borrow().as_ref().map_or(0, |x| x.raw)
@@ -453,7 +1049,14 @@ fn get_eu_stored(jni: &JNI, this: usize) -> i64 {
 #[dyn_abi]
 fn get_eu_capacity(jni: &JNI, this: usize) -> i64 {
     let lk = objs().mtx.lock(jni).unwrap();
-    let result = lk.read_tile::<Emitter>(energy_container_tile(&lk, BorrowedRef::new(jni, &this)).borrow()).eu_capacity(&*lk.tiers.borrow());
+    let result = lk.read_tile::<Emitter>(energy_container_tile(&lk, BorrowedRef::new(jni, &this)).borrow()).eu_capacity(&lk);
+    result
+}
+
+#[dyn_abi]
+fn get_input_amps(jni: &JNI, this: usize) -> i64 {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let result = lk.read_tile::<Emitter>(energy_container_tile(&lk, BorrowedRef::new(jni, &this)).borrow()).amps(&lk);
     result
 }
 
@@ -492,14 +1095,16 @@ fn accept_eu(jni: &JNI, this: usize, in_side: usize, volts: i64, amps: i64) -> i
         // TODO: smoke particle
         return 1;
     }
+    let (capacity, max_amps) = (emitter.eu_capacity(&lk), emitter.amps(&lk));
     let mut data = emitter.data.borrow_mut();
-    if data.energy + volts > emitter.eu_capacity(&tiers) {
+    let amps = amps.min(max_amps).min((capacity - data.energy) / volts);
+    if amps < 1 {
         return 0;
     }
-    data.energy += volts;
+    data.energy += volts * amps;
     tile.tile_mark_for_save();
-    emitter.stats.borrow_mut().eu_accepted += volts;
-    1
+    emitter.stats.borrow_mut().eu_accepted += volts * amps;
+    amps
 }
 
 #[dyn_abi]
@@ -508,9 +1113,10 @@ fn change_eu(jni: &JNI, this: usize, mut delta: i64) -> i64 {
     let tile = energy_container_tile(&lk, BorrowedRef::new(jni, &this));
     delta = {
         let emitter = lk.read_tile::<Emitter>(tile.borrow());
+        let capacity = emitter.eu_capacity(&lk);
         let mut data = emitter.data.borrow_mut();
         let old = data.energy;
-        data.energy = (old + delta).clamp(0, emitter.eu_capacity(&lk.tiers.borrow()));
+        data.energy = (old + delta).clamp(0, capacity);
         data.energy - old
     };
     if delta != 0 {
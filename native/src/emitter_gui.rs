@@ -1,41 +1,130 @@
 use crate::{
-    emitter_blocks::{Emitter, EmitterData},
-    global::GlobalMtx,
+    emitter_blocks::{Access, Emitter, EmitterData, EmitterStatus, Owner, Reach, MAX_NAME_LEN, REACH_RINGS, REACH_SECTORS},
+    global::{GlobalMtx, GlobalObjs},
     jvm::*,
-    mapping_base::MBOptExt,
+    mapping_base::{cs, MBOptExt},
     objs,
-    packets::{EmitterAction, C2S},
+    packets::{tick_menu, EmitterAction, C2S},
+    upgrades::UPGRADE_SLOTS,
     util::{
         cleaner::Cleanable,
-        client::{play_btn_click_sound, ClientExt},
-        geometry::{write_block_pos, Rect, DIR_ADJS, DIR_STEPS},
-        gui::{GUIExt, Menu, MenuType},
+        client::{client_ticks, play_btn_click_sound, ClientExt},
+        geometry::{write_block_pos, GeomExt, Rect, DIR_ADJS, DIR_STEPS},
+        gui::{held_modifiers, GUIExt, Menu, MenuType, Narration, KEY_DOWN, KEY_LEFT, KEY_RIGHT, KEY_UP, MOD_CONTROL, MOD_SHIFT},
+        strict_deserialize,
         tessellator::{Rounding, Stroke, Tessellator},
         tile::TileExt,
+        widgets::{Action, Arg, Kind, Layout, Response, Widgets},
     },
 };
-use alloc::sync::Arc;
+use alloc::{
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 use anyhow::{anyhow, ensure, Context, Result};
 use core::{
     any::Any,
-    cell::Cell,
-    f32::consts::{FRAC_PI_2, FRAC_PI_6, PI},
+    cell::{Cell, OnceCell, RefCell},
+    f32::consts::{FRAC_PI_2, FRAC_PI_6, PI, TAU},
 };
-use nalgebra::{point, vector, Matrix2, Point2, Point3, Rotation2, Vector2, Vector4};
-use num_traits::Float;
+use nalgebra::{point, vector, Affine3, Matrix2, Point2, Point3, Rotation2, Scale3, Translation3, UnitQuaternion, Vector2, Vector3, Vector4};
+use num_traits::{Euclid, Float};
+use serde::{Deserialize, Serialize};
+use simba::scalar::SupersetOf;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum WidgetId {
+    Label,
+    Zenith,
+    Azimuth,
+    Transfer,
+    Scan,
+    Access,
+    Name,
+    Energy,
+    Rate,
+    Target,
+    TargetPos,
+    TargetSide,
+}
+
+fn parse_deg(text: &str) -> Option<f32> { text.trim().parse::<f32>().ok().filter(|x| x.is_finite()) }
+fn parse_zenith(text: &str) -> Option<f32> { parse_deg(text).filter(|x| (0. ..=90.).contains(x)) }
+fn parse_azimuth(text: &str) -> Option<f32> { parse_deg(text).map(|x| x.rem_euclid(&360.)) }
+
+fn new_widgets() -> Widgets<WidgetId> {
+    let mut layout = Layout::new(point![6., GRID_BOTTOM + 2.], 3.);
+    let [zenith_label, zenith, _, scan] = layout.row(11., [41., 40., 5., 40.]);
+    let [azimuth_label, azimuth, _, access] = layout.row(11., [41., 40., 5., 40.]);
+    let [transfer] = layout.row(8., [138.]);
+    let [energy] = layout.row(9., [138.]);
+    let [rate] = layout.row(9., [138.]);
+    let [target] = layout.row(9., [138.]);
+    let [target_pos] = layout.row(9., [138.]);
+    let [target_side] = layout.row(9., [138.]);
+    let mut layout = Layout::new(point![-176., 4.], 2.);
+    let [name_label] = layout.row(9., [100.]);
+    let [name] = layout.row(12., [100.]);
+    Widgets::default()
+        .with(WidgetId::Label, zenith_label, Kind::Label(c"greg_emitters.zenith"))
+        .with(WidgetId::Zenith, zenith, Kind::Field { value: 0., unit: c"°", parse: parse_zenith })
+        .with(WidgetId::Scan, scan, Kind::Button(c"greg_emitters.scan"))
+        .with(WidgetId::Label, azimuth_label, Kind::Label(c"greg_emitters.azimuth"))
+        .with(WidgetId::Azimuth, azimuth, Kind::Field { value: 0., unit: c"°", parse: parse_azimuth })
+        .with(WidgetId::Access, access, Kind::Button(Access::Public.key()))
+        .with(WidgetId::Transfer, transfer, Kind::Toggle { label: c"greg_emitters.transfer_energy", on: false })
+        .with(WidgetId::Energy, energy, Kind::Text { key: c"", args: Vec::new() })
+        .with(WidgetId::Rate, rate, Kind::Text { key: c"", args: Vec::new() })
+        .with(WidgetId::Target, target, Kind::Text { key: c"", args: Vec::new() })
+        .with(WidgetId::TargetPos, target_pos, Kind::Text { key: c"", args: Vec::new() })
+        .with(WidgetId::TargetSide, target_side, Kind::Text { key: c"", args: Vec::new() })
+        .with(WidgetId::Label, name_label, Kind::Label(c"greg_emitters.name"))
+        .with(WidgetId::Name, name, Kind::Entry { text: String::new(), max_len: MAX_NAME_LEN })
+        .with(WidgetId::Label, label_rect(UPGRADES_Y), Kind::Label(c"greg_emitters.upgrades"))
+        .with(WidgetId::Label, label_rect(INVENTORY_Y), Kind::Label(c"container.inventory"))
+}
+
+const SIDE_KEYS: [&str; 6] = [
+    "greg_emitters.side.down",
+    "greg_emitters.side.up",
+    "greg_emitters.side.north",
+    "greg_emitters.side.south",
+    "greg_emitters.side.west",
+    "greg_emitters.side.east",
+];
+
+#[derive(Serialize, Deserialize)]
+enum MenuSync {
+    Status(EmitterStatus),
+    Reach(Vec<Reach>),
+}
 
 #[derive(Clone, Copy)]
-enum MouseState {
-    Released,
-    Dragging,
-    Checkbox,
+enum AxisLock {
+    Zenith(f32),
+    Azimuth(f32),
+}
+
+#[derive(Clone, Copy, Default)]
+struct Drag {
+    lock_from: Option<AxisLock>,
+    shown: Option<(f32, f32)>,
 }
 
 pub struct EmitterMenuType;
 pub struct EmitterMenu {
     pub tile: WeakGlobalRef<'static>,
-    mouse_state: Cell<MouseState>,
+    drag: Cell<Option<Drag>>,
+    widgets: Widgets<WidgetId>,
     view_tf: Matrix2<f32>,
+    // Last sent on the server, last received on the client.
+    status: RefCell<Option<EmitterStatus>>,
+    // Pending on the server, last received on the client.
+    pub reach: RefCell<Option<Vec<Reach>>>,
+    player_id: OnceCell<u128>,
 }
 
 impl MenuType for EmitterMenuType {
@@ -59,7 +148,16 @@ impl MenuType for EmitterMenuType {
                 view_tf.row_mut(0).apply(|x| *x = -*x)
             }
         }
-        Ok(Arc::new(EmitterMenu { tile: tile.new_weak_global_ref().unwrap(), mouse_state: MouseState::Released.into(), view_tf }))
+        let tile = tile.new_weak_global_ref().unwrap();
+        Ok(Arc::new(EmitterMenu {
+            tile,
+            drag: None.into(),
+            widgets: new_widgets(),
+            view_tf,
+            status: None.into(),
+            reach: None.into(),
+            player_id: OnceCell::new(),
+        }))
     }
 }
 
@@ -68,28 +166,145 @@ impl Cleanable for EmitterMenu {
 }
 
 const GRID_RADIUS: f32 = 56.;
-fn grid_center(rect: &Rect) -> Point2<f32> { rect.center() }
-fn checkbox_rect(rect: &Rect) -> Rect { Rect::from_center_size(point![rect.min.x + 10., rect.max.y - 10.], vector![8., 8.]) }
+const NUDGE_STEP: f32 = 0.1 * PI / 180.;
+const SCROLL_STEP: f32 = PI / 180.;
+const GRID_BOTTOM: f32 = 152.;
+const ZENITH_SNAP: f32 = 5. * PI / 180.;
+const AZIMUTH_SNAP: f32 = 15. * PI / 180.;
+const PREVIEW_SIZE: f32 = 64.;
+const PREVIEW_SCALE: f32 = 26.;
+const SLOTS_X: i32 = -171;
+const UPGRADES_Y: i32 = 86;
+const INVENTORY_Y: i32 = 120;
+const HOTBAR_Y: i32 = 178;
+const FIRST_INV_SLOT: i32 = UPGRADE_SLOTS as _;
+const N_INV_SLOTS: i32 = 36;
+fn grid_center(rect: &Rect) -> Point2<f32> { point![rect.center().x, rect.min.y + 85.] }
+fn preview_rect(rect: &Rect) -> Rect { Rect { min: rect.min - vector![PREVIEW_SIZE + 4., 0.], max: rect.min + vector![-4., PREVIEW_SIZE] } }
+fn name_rect(rect: &Rect) -> Rect { Rect { min: rect.min + vector![-180., 0.], max: rect.min + vector![-PREVIEW_SIZE - 8., 30.] } }
+fn slots_rect(rect: &Rect) -> Rect { Rect { min: rect.min + vector![-180., PREVIEW_SIZE + 4.], max: rect.min + vector![-4., 200.] } }
+fn label_rect(slots_y: i32) -> Rect { Rect { min: point![SLOTS_X - 1, slots_y - 12].cast(), max: point![-10, slots_y - 3].cast() } }
+fn slot_positions() -> impl Iterator<Item = Point2<i32>> {
+    let upgrades = (0..UPGRADE_SLOTS as i32).map(|i| point![SLOTS_X + i * 18, UPGRADES_Y]);
+    let inventory = (0..27).map(|i| point![SLOTS_X + i % 9 * 18, INVENTORY_Y + i / 9 * 18]);
+    upgrades.chain(inventory).chain((0..9).map(|i| point![SLOTS_X + i * 18, HOTBAR_Y]))
+}
 
 impl Menu for EmitterMenu {
     fn any(&self) -> &dyn Any { self }
-    fn still_valid(&self, player: BorrowedRef) -> bool {
+    fn still_valid(&self, lk: &GlobalMtx, player: BorrowedRef) -> bool {
         let Ok(tile) = self.tile.with_jni(player.jni).new_local_ref() else { return false };
-        tile.still_valid(&player)
+        let id = *self.player_id.get_or_init(|| Owner::id_of(&player));
+        tile.still_valid(&player) && lk.try_read_tile::<Emitter>(tile.borrow()).is_some_and(|x| x.can_access(&player, id))
+    }
+
+    fn tick(&self, lk: &GlobalMtx, player: BorrowedRef<'static, '_>) { tick_menu(lk, self, player) }
+
+    fn sync_data(&self, lk: &GlobalMtx, jni: &JNI) -> Option<Vec<u8>> {
+        if let Some(reach) = self.reach.borrow_mut().take() {
+            return Some(postcard::to_allocvec(&MenuSync::Reach(reach)).unwrap());
+        }
+        let tile = self.tile.with_jni(jni).new_local_ref().ok()?;
+        let status = lk.try_read_tile::<Emitter>(tile.borrow())?.status(lk, &tile.tile_level()?);
+        let mut sent = self.status.borrow_mut();
+        (sent.as_ref() != Some(&status)).then(|| postcard::to_allocvec(&MenuSync::Status(sent.insert(status).clone())).unwrap())
+    }
+
+    fn receive_data(&self, _lk: &GlobalMtx, data: &[u8]) -> Result<()> {
+        match strict_deserialize(data)? {
+            MenuSync::Status(x) => *self.status.borrow_mut() = Some(x),
+            MenuSync::Reach(x) => {
+                ensure!(x.len() == REACH_RINGS * REACH_SECTORS);
+                *self.reach.borrow_mut() = Some(x)
+            }
+        }
+        Ok(())
     }
 
     fn should_draw_dark_bg(&self) -> bool { false }
-    fn get_size(&self) -> Vector2<i32> { vector![150, 150 + 20] }
+    fn get_size(&self) -> Vector2<i32> { vector![150, 150 + 108] }
     fn get_offset(&self) -> Vector2<i32> { vector![150, 0] }
-    fn render_bg(&self, lk: &GlobalMtx, screen: BorrowedRef, gui: BorrowedRef, rect: Rect, cursor: Point2<i32>) {
+    fn contains(&self, rect: Rect, pos: Point2<f32>) -> bool {
+        [rect, preview_rect(&rect), name_rect(&rect), slots_rect(&rect)].iter().any(|x| x.contains(pos))
+    }
+
+    fn add_slots(&self, lk: &GlobalMtx, menu: BorrowedRef, inv: BorrowedRef) {
+        let GlobalObjs { mv, fmv, .. } = objs();
+        let Ok(tile) = self.tile.with_jni(menu.jni).new_local_ref() else { return };
+        let Some(handler) = lk.read_tile::<Emitter>(tile.borrow()).upgrade_handler.borrow().as_ref().map(|x| x.raw) else { return };
+        for (i, pos) in slot_positions().enumerate() {
+            let i = i as i32;
+            let slot = if i < FIRST_INV_SLOT {
+                fmv.slot_item_handler.with_jni(menu.jni).new_object(fmv.slot_item_handler_init, &[handler, i as _, pos.x as _, pos.y as _])
+            } else {
+                // Inventory indices start with the hotbar.
+                let index = (i - FIRST_INV_SLOT + 9) % N_INV_SLOTS;
+                mv.slot.with_jni(menu.jni).new_object(mv.slot_init, &[inv.raw, index as _, pos.x as _, pos.y as _])
+            };
+            menu.call_object_method(mv.container_menu_add_slot, &[slot.unwrap().raw]).unwrap();
+        }
+    }
+
+    fn quick_move_target(&self, index: i32) -> Option<(i32, i32, bool)> {
+        match index {
+            ..0 => None,
+            _ if index < FIRST_INV_SLOT => Some((FIRST_INV_SLOT, FIRST_INV_SLOT + N_INV_SLOTS, true)),
+            _ if index < FIRST_INV_SLOT + N_INV_SLOTS => Some((0, FIRST_INV_SLOT, false)),
+            _ => None,
+        }
+    }
+
+    fn render_bg(&self, lk: &GlobalMtx, screen: BorrowedRef, gui: BorrowedRef, rect: Rect, cursor: Point2<i32>, sub_tick: f32) {
         let Ok(tile) = self.tile.with_jni(gui.jni).new_local_ref() else { return };
+        let tile_pos = tile.tile_pos().read_vec3i();
         let Some(tile) = lk.try_read_tile::<Emitter>(tile.borrow()) else { return };
-        let EmitterData { zenith, azimuth, dir, disable_transfer, .. } = *tile.data.borrow();
+        let data = tile.data.borrow();
+        let EmitterData { zenith, azimuth, dir, disable_transfer, access, .. } = *data;
+        let name = data.name.clone().unwrap_or_default();
+        drop(data);
         let mut tess = Tessellator::new(gui.jni);
         tess.rect(rect, Rounding::same(4.), 0., vector![1., 1., 1., 0.5], &Stroke::new(1., vector![0., 0., 0., 1.]));
+        // The panel is already as tall as fits, so the preview and slots hang off its left side, between it and the crosshair.
+        let preview = preview_rect(&rect);
+        tess.rect(preview, Rounding::same(4.), 0., vector![1., 1., 1., 0.5], &Stroke::new(1., vector![0., 0., 0., 1.]));
+        tess.rect(name_rect(&rect), Rounding::same(4.), 0., vector![1., 1., 1., 0.5], &Stroke::new(1., vector![0., 0., 0., 1.]));
+        tess.rect(slots_rect(&rect), Rounding::same(4.), 0., vector![1., 1., 1., 0.5], &Stroke::new(1., vector![0., 0., 0., 1.]));
+        for pos in slot_positions() {
+            let min = rect.min + (pos.coords - vector![1, 1]).cast();
+            tess.rect(
+                Rect { min, max: min + vector![18., 18.] },
+                Rounding::ZERO,
+                0.,
+                vector![0.55, 0.55, 0.55, 1.],
+                &Stroke::new(1., vector![0.2, 0.2, 0.2, 1.]),
+            )
+        }
         let center = grid_center(&rect);
         let grid_stroke = Stroke::new(1., vector![0.25, 0.25, 0.25, 1.]);
 
+        // Reachability
+        if let Some(reach) = &*self.reach.borrow() {
+            let ring_width = GRID_RADIUS / REACH_RINGS as f32;
+            let sector = TAU / REACH_SECTORS as f32;
+            let at = |radius: f32, azimuth: f32| center + self.view_tf * Rotation2::new(-azimuth) * vector![radius, 0.];
+            let no_stroke = Stroke::new(0., Vector4::zeros());
+            for (i, reach) in reach.iter().enumerate() {
+                let fill = match reach {
+                    Reach::Nothing => continue,
+                    Reach::Inert => vector![0.8, 0.3, 0.2, 0.3],
+                    Reach::Receiver => vector![0.2, 0.8, 0.2, 0.5],
+                };
+                let (ring, azimuth) = ((i / REACH_SECTORS) as f32, (i % REACH_SECTORS) as f32 * sector);
+                let (inner, outer) = (ring * ring_width, (ring + 1.) * ring_width);
+                let mut pts = Vec::with_capacity(5);
+                pts.extend([at(inner, azimuth), at(outer, azimuth), at(outer, azimuth + sector * 0.5), at(outer, azimuth + sector)]);
+                if ring > 0. {
+                    pts.push(at(inner, azimuth + sector))
+                }
+                tess.path(&pts, true, fill, &no_stroke)
+            }
+        }
+
         // Zenith Grid
         for i in 1..=3 {
             let radius = (i * 30) as f32 * (GRID_RADIUS / 90.);
@@ -105,21 +320,6 @@ impl Menu for EmitterMenu {
         }
         let pos = center + self.view_tf * Rotation2::new(-azimuth) * vector![zenith * (GRID_RADIUS / FRAC_PI_2), 0.];
         tess.circle(pos, 4., vector![1., 0., 0., 1.], &Stroke::new(1., vector![0., 0., 0., 1.]));
-
-        // Checkbox
-        let cb_rect = checkbox_rect(&rect);
-        let cursor = cursor.cast::<f32>();
-        let color = match self.mouse_state.get() {
-            MouseState::Checkbox => vector![0.5, 0.5, 0.5, 0.5],
-            MouseState::Released if rect.contains(cursor) && cursor.y >= cb_rect.min.y => vector![0.8, 0.8, 1., 0.5],
-            _ => vector![1., 1., 1., 0.5],
-        };
-        tess.rect(cb_rect, Rounding::same(1.), 0., color, &Stroke::new(1., vector![0., 0., 0., 1.]));
-        if !disable_transfer {
-            let center = cb_rect.center();
-            let pts = [center + vector![-2.7, 0.5], center + vector![-0.7, 2.7], center + vector![2.7, -2.7]];
-            tess.path(&pts, false, Vector4::zeros(), &Stroke::new(1., vector![0., 0., 0., 1.]))
-        }
         gui.gui_draw_mesh(&mut tess.mesh);
 
         // Azimuth Label
@@ -135,54 +335,235 @@ impl Menu for EmitterMenu {
             div = step * div
         }
 
-        // Checkbox Label
-        let text = gui.jni.new_utf(c"greg_emitters.transfer_energy").unwrap().translatable().to_formatted();
-        gui.gui_draw_formatted(&font, &text, (cb_rect.max.x + 4.) as _, cb_rect.min.y as _, 0, false);
+        // Snapped Value
+        if let Some(Drag { shown: Some((zenith, azimuth)), .. }) = self.drag.get() {
+            let text = cs(format!("{:.1}° {:.1}°", zenith.to_degrees(), azimuth.to_degrees()));
+            let text = gui.jni.new_utf(&text).unwrap().literal().to_formatted();
+            gui.gui_draw_formatted(&font, &text, (pos.x + 6.) as _, (pos.y - 12.) as _, 0, false)
+        }
+
+        // 3D Preview
+        let mvc = objs().mv.client.uref();
+        let source = gui.call_object_method(mvc.gui_graphics_buffer_source, &[]).unwrap().unwrap();
+        let gui_pose = gui.get_object_field(mvc.gui_graphics_pose).unwrap().last_pose().read_pose();
+        let y_rot = mvc.mc_inst.with_jni(gui.jni).get_object_field(mvc.mc_player).unwrap().get_float_field(objs().mv.entity_y_rot);
+        let center = preview.center();
+        let tf = gui_pose
+            * Translation3::new(center.x, center.y, 100.)
+            * Affine3::from_subset(&Scale3::new(PREVIEW_SCALE, -PREVIEW_SCALE, PREVIEW_SCALE))
+            * UnitQuaternion::from_axis_angle(&Vector3::x_axis(), FRAC_PI_6)
+            * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), (y_rot + 180.).to_radians())
+            * Translation3::new(-0.5, -0.5, -0.5);
+        tile.render_preview(lk, &source, tf, client_ticks(gui.jni) as f32 + sub_tick);
+
+        // Widgets
+        self.widgets.set_value(WidgetId::Zenith, zenith.to_degrees());
+        self.widgets.set_value(WidgetId::Azimuth, azimuth.to_degrees());
+        self.widgets.set_on(WidgetId::Transfer, !disable_transfer);
+        self.widgets.set_label(WidgetId::Access, access.key());
+        self.widgets.set_entry(WidgetId::Name, &name);
+        if let Some(status) = &*self.status.borrow() {
+            self.set_status(status, tile_pos)
+        }
+        self.widgets.render(screen, gui, rect.min, cursor)
     }
 
-    fn mouse_clicked(&self, _lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>, button: i32) -> bool {
-        let handled = button == 0 && rect.contains(pos);
-        if handled {
-            if pos.y >= checkbox_rect(&rect).min.y {
-                self.mouse_state.set(MouseState::Checkbox)
-            } else {
-                self.mouse_state.set(MouseState::Dragging);
-                self.send_attitude(menu, rect, pos)
-            }
+    fn mouse_clicked(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>, button: i32) -> bool {
+        let response = self.widgets.mouse_clicked(menu.jni, rect.min, pos, button);
+        if response.is_handled() {
+            return self.handle(lk, menu, response);
+        }
+        if button != 0 || !rect.contains(pos) {
+            return false;
+        }
+        if pos.y < rect.min.y + GRID_BOTTOM {
+            self.drag.set(Some(Drag::default()));
             play_btn_click_sound(menu.jni);
+            self.send_attitude(lk, menu, rect, pos)
         }
-        handled
+        true
     }
 
-    fn mouse_dragged(&self, _lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>) -> bool {
-        match self.mouse_state.get() {
-            MouseState::Released => return false,
-            MouseState::Dragging => self.send_attitude(menu, rect, pos),
-            MouseState::Checkbox => (),
+    fn mouse_dragged(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>) -> bool {
+        let response = self.widgets.mouse_dragged(rect.min, pos);
+        if response.is_handled() {
+            return self.handle(lk, menu, response);
+        }
+        if self.drag.get().is_none() {
+            return false;
         }
+        self.send_attitude(lk, menu, rect, pos);
         true
     }
 
-    fn mouse_released(&self, lk: &GlobalMtx, menu: BorrowedRef, button: i32) -> bool {
-        let 0 = button else { return false };
-        let MouseState::Checkbox = self.mouse_state.replace(MouseState::Released) else { return false };
-        let Ok(tile) = self.tile.with_jni(menu.jni).new_local_ref() else { return false };
-        let Some(tile) = lk.try_read_tile::<Emitter>(tile.borrow()) else { return false };
-        let menu_id = menu.get_int_field(objs().mv.container_menu_id);
-        let value = !tile.data.borrow().disable_transfer;
-        objs().net_defs.send_c2s(menu.jni, &C2S { menu_id, action: EmitterAction::SetDisableTransfer(value) });
-        false
+    fn mouse_released(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>, button: i32) -> bool {
+        if button == 0 {
+            self.drag.set(None)
+        }
+        let response = self.widgets.mouse_released(rect.min, pos, button);
+        response.is_handled() && self.handle(lk, menu, response)
+    }
+
+    fn is_focused(&self) -> bool { self.widgets.is_focused() }
+
+    fn update_narration(&self, lk: &GlobalMtx, output: BorrowedRef) {
+        let jni = output.jni;
+        let Ok(tile) = self.tile.with_jni(jni).new_local_ref() else { return };
+        let Some(tile) = lk.try_read_tile::<Emitter>(tile.borrow()) else { return };
+        let EmitterData { zenith, azimuth, disable_transfer, .. } = *tile.data.borrow();
+        let deg = |x: f32| jni.new_utf(&cs(format!("{:.1}", x.to_degrees()))).unwrap();
+        let transfer = jni.new_utf(if disable_transfer { c"options.off" } else { c"options.on" }).unwrap().translatable();
+        let state = jni.new_utf(c"greg_emitters.narration.state").unwrap().translatable_with(&[deg(zenith), deg(azimuth), transfer]);
+        output.narration_add(Narration::Position, &state);
+        let hint = match self.widgets.focused() {
+            Some(WidgetId::Zenith) => c"greg_emitters.narration.zenith",
+            Some(WidgetId::Azimuth) => c"greg_emitters.narration.azimuth",
+            Some(WidgetId::Scan) => c"greg_emitters.narration.scan",
+            Some(WidgetId::Transfer) => c"greg_emitters.narration.transfer",
+            Some(WidgetId::Access) => c"greg_emitters.narration.access",
+            Some(WidgetId::Name) => c"greg_emitters.narration.name",
+            _ => c"greg_emitters.narration.usage",
+        };
+        output.narration_add(Narration::Hint, &jni.new_utf(hint).unwrap().translatable())
+    }
+
+    fn key_pressed(&self, lk: &GlobalMtx, menu: BorrowedRef, key: i32, modifiers: i32) -> bool {
+        let response = self.widgets.key_pressed(key);
+        if response.is_handled() {
+            return self.handle(lk, menu, response);
+        }
+        let step = if modifiers & MOD_SHIFT != 0 { NUDGE_STEP * 10. } else { NUDGE_STEP };
+        let (zenith, azimuth) = match key {
+            KEY_UP => (step, 0.),
+            KEY_DOWN => (-step, 0.),
+            KEY_RIGHT => (0., step),
+            KEY_LEFT => (0., -step),
+            _ => return false,
+        };
+        self.nudge(lk, menu, zenith, azimuth);
+        true
+    }
+
+    fn char_typed(&self, lk: &GlobalMtx, menu: BorrowedRef, ch: char) -> bool {
+        let response = self.widgets.char_typed(ch);
+        response.is_handled() && self.handle(lk, menu, response)
+    }
+
+    fn mouse_scrolled(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>, delta: f32) -> bool {
+        let true = (pos - grid_center(&rect)).norm() <= GRID_RADIUS else { return false };
+        self.nudge(lk, menu, delta * SCROLL_STEP, 0.);
+        true
     }
 }
 
 impl EmitterMenu {
-    pub fn new_server(tile: WeakGlobalRef<'static>) -> Self { Self { tile, mouse_state: MouseState::Released.into(), view_tf: <_>::default() } }
+    pub fn new_server(tile: WeakGlobalRef<'static>) -> Self {
+        Self {
+            tile,
+            drag: None.into(),
+            widgets: new_widgets(),
+            view_tf: <_>::default(),
+            status: None.into(),
+            reach: None.into(),
+            player_id: OnceCell::new(),
+        }
+    }
+
+    fn set_status(&self, status: &EmitterStatus, tile_pos: Point3<i32>) {
+        let lit = Arg::Literal;
+        let per_tick = |x: i64| lit(format!("{:.1}", x as f32 / 20.));
+        self.widgets.set_text(
+            WidgetId::Energy,
+            c"greg_emitters.status.energy",
+            vec![lit(status.energy.to_string()), lit(status.capacity.to_string())],
+        );
+        self.widgets.set_text(WidgetId::Rate, c"greg_emitters.status.rate", vec![per_tick(status.eu_in), per_tick(status.eu_out)]);
+        let Some(target) = &status.target else {
+            self.widgets.set_text(WidgetId::Target, c"greg_emitters.status.no_target", Vec::new());
+            self.widgets.set_text(WidgetId::TargetPos, c"", Vec::new());
+            self.widgets.set_text(WidgetId::TargetSide, c"", Vec::new());
+            return;
+        };
+        let key = if target.receiver { c"greg_emitters.status.target" } else { c"greg_emitters.status.blocked" };
+        let name = match &target.name {
+            Some(name) => Arg::Literal(name.clone()),
+            None => Arg::Translated(target.desc_id.clone()),
+        };
+        self.widgets.set_text(WidgetId::Target, key, vec![name]);
+        let pos = target.pos;
+        let distance = lit(format!("{:.1}", (pos - tile_pos).cast::<f32>().norm()));
+        let coords = vec![lit(pos.x.to_string()), lit(pos.y.to_string()), lit(pos.z.to_string()), distance];
+        self.widgets.set_text(WidgetId::TargetPos, c"greg_emitters.status.position", coords);
+        let key = if target.receiver { c"greg_emitters.status.receiver" } else { c"greg_emitters.status.not_receiver" };
+        self.widgets.set_text(WidgetId::TargetSide, key, vec![Arg::Translated(SIDE_KEYS[target.side as usize].into())])
+    }
 
-    fn send_attitude(&self, menu: BorrowedRef, rect: Rect, pos: Point2<f32>) {
+    fn handle(&self, lk: &GlobalMtx, menu: BorrowedRef, response: Response<WidgetId>) -> bool {
+        let Response::Action(id, action) = response else { return response.is_handled() };
+        let Some((zenith, azimuth)) = self.attitude(lk, menu.jni) else { return true };
+        let action = match (id, action) {
+            (WidgetId::Zenith, Action::Changed(x)) => EmitterAction::SetAttitude { zenith: x.to_radians().min(FRAC_PI_2), azimuth },
+            (WidgetId::Azimuth, Action::Changed(x)) => EmitterAction::SetAttitude { zenith, azimuth: x.to_radians() },
+            (WidgetId::Transfer, Action::Toggled(x)) => EmitterAction::SetDisableTransfer(!x),
+            (WidgetId::Scan, Action::Clicked) => EmitterAction::ScanReach,
+            (WidgetId::Access, Action::Clicked) => EmitterAction::SetAccess(self.access(lk, menu.jni).unwrap_or_default().next()),
+            (WidgetId::Name, Action::Entered(x)) => EmitterAction::SetName(x),
+            _ => return true,
+        };
+        send_action(menu, action);
+        true
+    }
+
+    fn attitude(&self, lk: &GlobalMtx, jni: &JNI) -> Option<(f32, f32)> {
+        let tile = self.tile.with_jni(jni).new_local_ref().ok()?;
+        let tile = lk.try_read_tile::<Emitter>(tile.borrow())?;
+        let data = tile.data.borrow();
+        Some((data.zenith, data.azimuth))
+    }
+
+    fn access(&self, lk: &GlobalMtx, jni: &JNI) -> Option<Access> {
+        let tile = self.tile.with_jni(jni).new_local_ref().ok()?;
+        let access = lk.try_read_tile::<Emitter>(tile.borrow())?.data.borrow().access;
+        Some(access)
+    }
+
+    fn send_attitude(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>) {
+        let Some(mut drag) = self.drag.get() else { return };
         let dir = self.view_tf.transpose() * (pos - grid_center(&rect));
-        let zenith = dir.norm() * (FRAC_PI_2 / GRID_RADIUS);
-        let azimuth = -libm::atan2f(dir.y, dir.x);
-        let menu_id = menu.get_int_field(objs().mv.container_menu_id);
-        objs().net_defs.send_c2s(menu.jni, &C2S { menu_id, action: EmitterAction::SetAttitude { zenith, azimuth } });
+        let mut zenith = (dir.norm() * (FRAC_PI_2 / GRID_RADIUS)).min(FRAC_PI_2);
+        let mut azimuth = (-libm::atan2f(dir.y, dir.x)).rem_euclid(&TAU);
+        let modifiers = held_modifiers(menu.jni);
+        if modifiers & MOD_SHIFT != 0 {
+            zenith = (zenith / ZENITH_SNAP).round() * ZENITH_SNAP;
+            azimuth = ((azimuth / AZIMUTH_SNAP).round() * AZIMUTH_SNAP).rem_euclid(&TAU)
+        }
+        drag.lock_from = match modifiers & MOD_CONTROL {
+            0 => None,
+            _ => drag.lock_from.or_else(|| {
+                let (from_zenith, from_azimuth) = self.attitude(lk, menu.jni)?;
+                let d_zenith = (zenith - from_zenith).abs();
+                let d_azimuth = ((azimuth - from_azimuth + PI).rem_euclid(&TAU) - PI).abs() * zenith;
+                Some(if d_zenith >= d_azimuth { AxisLock::Azimuth(from_azimuth) } else { AxisLock::Zenith(from_zenith) })
+            }),
+        };
+        match drag.lock_from {
+            Some(AxisLock::Zenith(x)) => zenith = x,
+            Some(AxisLock::Azimuth(x)) => azimuth = x,
+            None => (),
+        }
+        drag.shown = (modifiers != 0).then_some((zenith, azimuth));
+        self.drag.set(Some(drag));
+        send_action(menu, EmitterAction::SetAttitude { zenith, azimuth })
+    }
+
+    fn nudge(&self, lk: &GlobalMtx, menu: BorrowedRef, d_zenith: f32, d_azimuth: f32) {
+        let Some((zenith, azimuth)) = self.attitude(lk, menu.jni) else { return };
+        let (zenith, azimuth) = ((zenith + d_zenith).clamp(0., FRAC_PI_2), (azimuth + d_azimuth).rem_euclid(&TAU));
+        send_action(menu, EmitterAction::SetAttitude { zenith, azimuth })
     }
 }
+
+fn send_action(menu: BorrowedRef, action: EmitterAction) {
+    let menu_id = menu.get_int_field(objs().mv.container_menu_id);
+    objs().net_defs.send_c2s(menu.jni, &C2S { menu_id, action });
+}
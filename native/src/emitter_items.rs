@@ -1,21 +1,36 @@
 use crate::{
-    emitter_blocks::Emitter,
-    global::GlobalObjs,
+    asm::*,
+    beams::{render_dashed_beam, trace_beam},
+    emitter_blocks::{clean_name, read_dir_tag, render_emitter, Emitter, EmitterData, Owner},
+    global::{warn, GlobalMtx, GlobalObjs, Tier},
     jvm::*,
+    mapping_base::{cs, MBOptExt},
     objs,
     util::{
         cleaner::Cleanable,
+        client::{client_ticks, ClientExt, SolidRenderer, FULL_BRIGHT, NO_OVERLAY},
         geometry::{write_block_pos, write_dir, GeomExt, DIR_STEPS},
+        gui::GUIExt,
+        nbt::{new_compound, NBTExt},
         tile::TileExt,
         ClassBuilder, ThinWrapper,
     },
 };
-use alloc::sync::Arc;
+use alloc::{
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use anyhow::Result;
+use core::ffi::CStr;
 use macros::dyn_abi;
+use nalgebra::{Point3, Translation3};
 
 pub struct EmitterItems {
     pub item: GlobalRef<'static>,
     item_factory: ThinWrapper<ItemFactory>,
+    pub renderer: Option<GlobalRef<'static>>,
 }
 
 struct ItemFactory {
@@ -28,22 +43,74 @@ impl Cleanable for ItemFactory {
 
 impl EmitterItems {
     pub fn new(jni: &'static JNI) -> Self {
-        let GlobalObjs { cn, mn, gcn, .. } = objs();
+        let GlobalObjs { av, cn, mn, mv, gcn, fcn, fmn, .. } = objs();
         let item = ClassBuilder::new_2(jni, &cn.block_item.slash)
             .native_2(&mn.item_get_desc_id, get_desc_id_dyn())
             .native_2(&mn.block_item_place_block, place_block_dyn())
+            .native_2(&mn.item_append_hover_text, append_hover_text_dyn())
             .define_empty();
         let item_factory = ClassBuilder::new_2(jni, c"java/lang/Object")
             .interfaces([&*gcn.non_null_fn.slash])
             .native_1(c"apply", c"(Ljava/lang/Object;)Ljava/lang/Object;", build_item_dyn())
             .define_thin()
             .wrap::<ItemFactory>();
-        Self { item, item_factory }
+        let renderer = mv.client.is_some().then(|| {
+            ClassBuilder::new_2(jni, &cn.item_renderer.slash)
+                .interfaces([&*fcn.client_item_exts.slash])
+                .native_2(&mn.item_renderer_render, render_item_dyn())
+                .insns(&fmn.client_item_exts_get_renderer, [av.new_var_insn(jni, OP_ALOAD, 0).unwrap(), av.new_insn(jni, OP_ARETURN).unwrap()])
+                .define_empty()
+                .alloc_object()
+                .unwrap()
+                .new_global_ref()
+                .unwrap()
+        });
+        Self { item, item_factory, renderer }
     }
 
     pub fn new_item_factory<'a>(&self, jni: &'a JNI, tier: u8) -> LocalRef<'a> { self.item_factory.new_obj(jni, ItemFactory { tier }.into()) }
 }
 
+const KEY_ITEM_DATA: &CStr = c"emitter";
+
+pub fn write_item_data<'a>(stack: &impl JRef<'a>, data: &EmitterData) {
+    let mv = &objs().mv;
+    // As if renamed in an anvil, which is where placing it takes the name from.
+    if let Some(name) = &data.name {
+        let name = stack.jni().new_utf(&cs(name.as_str())).unwrap().literal();
+        stack.call_object_method(mv.item_stack_set_component, &[mv.data_components_custom_name.raw, name.raw]).unwrap();
+    }
+    // Fresh emitters keep stacking with newly crafted ones.
+    let true = data.has_settings() else { return };
+    let (root, tag) = (new_compound(stack.jni()), new_compound(stack.jni()));
+    tag.compound_put_byte(c"dir", data.dir as _);
+    data.write_settings(tag.borrow());
+    root.compound_put_compound(KEY_ITEM_DATA, &tag);
+    let custom_data = mv.custom_data.with_jni(stack.jni()).call_static_object_method(mv.custom_data_of, &[root.raw]).unwrap().unwrap();
+    stack.call_object_method(mv.item_stack_set_component, &[mv.data_components_custom_data.raw, custom_data.raw]).unwrap();
+}
+
+fn read_item_name<'a>(stack: &impl JRef<'a>) -> Option<String> {
+    let mv = &objs().mv;
+    let name = stack.call_object_method(mv.item_stack_get_component, &[mv.data_components_custom_name.raw]).unwrap()?;
+    let name = name.call_object_method(mv.chat_component_get_string, &[]).unwrap().unwrap();
+    clean_name(&String::from_utf8_lossy(&name.utf_chars().unwrap()))
+}
+
+pub fn read_item_data<'a>(stack: &impl JRef<'a>) -> Option<Result<EmitterData>> {
+    let mv = &objs().mv;
+    let custom_data = stack.call_object_method(mv.item_stack_get_component, &[mv.data_components_custom_data.raw]).unwrap()?;
+    let root = custom_data.call_object_method(mv.custom_data_copy_tag, &[]).unwrap().unwrap();
+    let tag = root.compound_get_compound(KEY_ITEM_DATA)?;
+    let mut data = EmitterData::default();
+    let result: Result<_> = try {
+        data.dir = read_dir_tag(tag.borrow())?.unwrap_or_default();
+        data.read_settings(tag.borrow())?;
+        data
+    };
+    Some(result)
+}
+
 #[dyn_abi]
 fn build_item(jni: &'static JNI, this: usize, props: usize) -> usize {
     let lk = objs().mtx.lock(jni).unwrap();
@@ -69,20 +136,181 @@ fn place_block(jni: &'static JNI, this: usize, ctx: usize, state: usize) -> bool
     let ctx = BorrowedRef::new(jni, &ctx);
     let level = ctx.call_object_method(mv.use_on_ctx_get_level, &[]).unwrap().unwrap();
     let false = level.level_is_client() else { return true };
-    let mut pos = ctx.call_object_method(mv.use_on_ctx_get_clicked_pos, &[]).unwrap().unwrap();
-    let dir_obj = ctx.call_object_method(mv.use_on_ctx_get_clicked_face, &[]).unwrap().unwrap();
-    let dir = dir_obj.read_dir();
+    let pos = ctx.call_object_method(mv.use_on_ctx_get_clicked_pos, &[]).unwrap().unwrap();
     let tile = level.tile_at(&pos).unwrap();
     let lk = mtx.lock(jni).unwrap();
-    lk.read_tile::<Emitter>(tile.borrow()).data.borrow_mut().dir = dir;
+    let emitter = lk.read_tile::<Emitter>(tile.borrow());
+    let stack = ctx.call_object_method(mv.use_on_ctx_get_item, &[]).unwrap().unwrap();
+    let mut data = emitter.data.borrow_mut();
+    match read_item_data(&stack) {
+        Some(Ok(mut item_data)) => {
+            // Facing comes from the block state, so the tile already has it.
+            item_data.rebase(data.dir);
+            *data = item_data
+        }
+        Some(Err(e)) => warn(jni, &cs(format!("Failed to restore emitter settings at {}: {e:?}", pos.read_vec3i()))),
+        None => (),
+    }
+    data.owner = ctx.call_object_method(mv.use_on_ctx_get_player, &[]).unwrap().map(|x| Owner::of(&x));
+    data.name = read_item_name(&stack);
+    drop(data);
+    let capacity = emitter.eu_capacity(&lk);
+    let mut data = emitter.data.borrow_mut();
+    data.energy = data.energy.min(capacity);
+    tile.tile_mark_for_save();
+    let dir = data.dir;
+    drop(data);
+    set_pipe_conn(&lk, &level, pos.read_vec3i(), &tile, dir, true);
+    true
+}
+
+// Connects or disconnects a GT cable behind an emitter facing `dir`, as GT only does it for its own blocks.
+pub fn set_pipe_conn<'a>(lk: &GlobalMtx, level: &impl JRef<'a>, src: Point3<i32>, tile: &impl JRef<'a>, dir: u8, connect: bool) {
+    let jni = level.jni();
     let opp = dir ^ 1;
-    pos = write_block_pos(jni, pos.read_vec3i() + DIR_STEPS[opp as usize]);
+    let pos = write_block_pos(jni, src + DIR_STEPS[opp as usize]);
     let pipe_block = level.block_state_at(&pos).block_state_get_block();
     let gmv = lk.gmv.get().unwrap();
-    let true = pipe_block.is_instance_of(gmv.pipe_block.raw) else { return true };
-    let Some(pipe_node) = pipe_block.call_object_method(gmv.pipe_block_get_node, &[level.raw, pos.raw]).unwrap() else { return true };
-    let opp_obj = write_dir(jni, opp);
-    let true = pipe_block.call_bool_method(gmv.pipe_block_can_connect, &[pipe_node.raw, opp_obj.raw, tile.raw]).unwrap() else { return true };
-    pipe_node.call_void_method(gmv.pipe_node_set_conn, &[dir_obj.raw, 1, 0]).unwrap();
-    true
+    let true = pipe_block.is_instance_of(gmv.pipe_block.raw) else { return };
+    let Some(pipe_node) = pipe_block.call_object_method(gmv.pipe_block_get_node, &[level.raw(), pos.raw]).unwrap() else { return };
+    if connect {
+        let true = pipe_block.call_bool_method(gmv.pipe_block_can_connect, &[pipe_node.raw, write_dir(jni, opp).raw, tile.raw()]).unwrap() else {
+            return;
+        };
+    }
+    pipe_node.call_void_method(gmv.pipe_node_set_conn, &[write_dir(jni, dir).raw, connect as _, 0]).unwrap()
+}
+
+#[dyn_abi]
+fn append_hover_text(jni: &JNI, _this: usize, stack: usize, _ctx: usize, lines: usize, _flag: usize) {
+    let GlobalObjs { mv, mtx, .. } = objs();
+    let stack = BorrowedRef::new(jni, &stack);
+    let lit = |x: &str| jni.new_utf(&cs(x)).unwrap();
+    let num = |x: i64| lit(&x.to_string());
+    let lk = mtx.lock(jni).unwrap();
+    let Some(tier) = tier_of_stack(&lk, &stack) else { return };
+    let tiers = lk.tiers.borrow();
+    let Tier { volt, ref name, .. } = tiers[tier as usize];
+    let balance = lk.balance.get();
+    let item_data = read_item_data(&stack).and_then(Result::ok);
+    let data = item_data.clone().unwrap_or_default();
+    let mut texts = Vec::from([
+        lit("greg_emitters.tooltip.usage").translatable(),
+        lit("greg_emitters.tooltip.voltage").translatable_with(&[num(volt), lit(&name.to_uppercase())]),
+        lit("greg_emitters.tooltip.amperage").translatable_with(&[num(data.amps(&balance))]),
+        lit("greg_emitters.tooltip.idle_drain").translatable_with(&[num(data.idle_drain(&balance))]),
+        lit("greg_emitters.tooltip.capacity").translatable_with(&[num(data.eu_capacity(volt, &balance))]),
+        lit("greg_emitters.tooltip.range").translatable(),
+        lit("greg_emitters.tooltip.loss").translatable(),
+    ]);
+    if item_data.is_some() {
+        let deg = |x: f32| lit(&format!("{:.1}", x.to_degrees()));
+        texts.push(lit("greg_emitters.tooltip.attitude").translatable_with(&[deg(data.zenith), deg(data.azimuth)]));
+        texts.push(lit("greg_emitters.tooltip.energy").translatable_with(&[num(data.energy)]));
+        if data.disable_transfer {
+            texts.push(lit("greg_emitters.tooltip.transfer_disabled").translatable())
+        }
+        for kind in data.upgrades.iter().flatten() {
+            let upgrade = lit(&format!("item.greg_emitters.{}", kind.id())).translatable();
+            texts.push(lit("greg_emitters.tooltip.upgrade").translatable_with(&[upgrade]))
+        }
+    }
+    let gray = mv.chat_fmt.with_jni(jni).call_static_object_method(mv.chat_fmt_from_code, &[b'7' as _]).unwrap().unwrap();
+    for text in texts {
+        let text = text.call_object_method(mv.chat_mutable_component_with_style, &[gray.raw]).unwrap().unwrap();
+        BorrowedRef::new(jni, &lines).call_bool_method(mv.list_add, &[text.raw]).unwrap();
+    }
+}
+
+#[dyn_abi]
+fn render_item(jni: &JNI, _: usize, stack: usize, _ctx: usize, pose_stack: usize, buffer_source: usize, light: i32, overlay: i32) {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let Some(tier) = tier_of_stack(&lk, &BorrowedRef::new(jni, &stack)) else { return };
+    let sr = SolidRenderer::new(&lk, &BorrowedRef::new(jni, &buffer_source), light, overlay);
+    let pose = BorrowedRef::new(jni, &pose_stack).last_pose().read_pose();
+    render_emitter(&lk, sr, pose, tier, 1, 0., 0.)
+}
+
+fn tier_of_stack<'a>(lk: &GlobalMtx, stack: &impl JRef<'a>) -> Option<u8> {
+    let item = stack.call_object_method(objs().mv.item_stack_get_item, &[]).unwrap().unwrap();
+    lk.tiers.borrow().iter().position(|x| x.emitter_item.get().is_some_and(|x| item.is_same_object(x.raw))).map(|x| x as _)
+}
+
+pub struct PlacementPreview {
+    level: GlobalRef<'static>,
+    clicked: Point3<i32>,
+    dir: u8,
+    tick: i32,
+    src: Point3<i32>,
+    dst: Point3<f64>,
+    hit: Option<(Point3<i32>, u8)>,
+}
+
+fn trace_placement_preview<'a>(
+    lk: &GlobalMtx,
+    level: &impl JRef<'a>,
+    clicked: Point3<i32>,
+    dir: u8,
+) -> (Point3<i32>, Point3<f64>, Option<(Point3<i32>, u8)>) {
+    let jni = level.jni();
+    let tick = client_ticks(jni);
+    if let Some(x) = &lk.client_state.borrow().placement_preview {
+        if (x.clicked, x.dir, x.tick) == (clicked, dir, tick) && level.is_same_object(x.level.raw) {
+            return (x.src, x.dst, x.hit);
+        }
+    }
+    let replace = level.block_state_at(&write_block_pos(jni, clicked)).call_bool_method(objs().mv.block_state_can_be_replaced, &[]).unwrap();
+    let src = if replace { clicked } else { clicked + DIR_STEPS[dir as usize] };
+    let (dst, hit) = trace_beam(level, src, EmitterData { dir, ..<_>::default() }.compute_dir(), |_| ());
+    let level = level.new_global_ref().unwrap();
+    lk.client_state.borrow_mut().placement_preview = Some(PlacementPreview { level, clicked, dir, tick, src, dst, hit });
+    (src, dst, hit)
+}
+
+pub fn render_placement_preview<'a>(lk: &GlobalMtx, pose_stack: &impl JRef<'a>, source: &impl JRef<'a>, camera_pos: Point3<f64>) {
+    let jni = pose_stack.jni();
+    let mv = &objs().mv;
+    let mvc = mv.client.uref();
+    let mc = mvc.mc_inst.with_jni(jni);
+    let Some(player) = mc.get_object_field(mvc.mc_player) else { return };
+    let mut hands = [mv.living_entity_main_hand_item, mv.living_entity_offhand_item].into_iter();
+    let Some(tier) = hands.find_map(|x| tier_of_stack(lk, &player.call_object_method(x, &[]).unwrap().unwrap())) else {
+        return lk.client_state.borrow_mut().placement_preview = None;
+    };
+    let Some(target) = mc.get_object_field(mvc.mc_hit_result) else { return };
+    let true = target.is_instance_of(mv.block_hit_result.raw) else { return };
+    let false = target.get_bool_field(mv.block_hit_result_miss) else { return };
+    let dir = target.get_object_field(mv.block_hit_result_dir).unwrap().read_dir();
+    let clicked = target.get_object_field(mv.block_hit_result_block_pos).unwrap().read_vec3i();
+    let level = player.call_object_method(mv.entity_level, &[]).unwrap().unwrap();
+    let (src, dst, hit) = trace_placement_preview(lk, &level, clicked, dir);
+    let pose = pose_stack.last_pose();
+    let rel = |p: Point3<f64>| (p - camera_pos).cast::<f32>();
+    let sheet = lk.sheets_translucent.get().unwrap();
+    let sr = SolidRenderer::translucent(lk, source, FULL_BRIGHT, NO_OVERLAY, 0x80);
+    render_emitter(lk, sr, pose.read_pose() * Translation3::from(rel(src.cast())), tier, dir, 0., 0.);
+    source.call_void_method(mvc.buffer_source_end_batch, &[sheet.raw]).unwrap();
+    let color = lk.tiers.borrow()[tier as usize].color;
+    let vb = source.call_object_method(mvc.multi_buffer_source_get_buffer, &[mvc.render_type_lightning.raw]).unwrap().unwrap();
+    render_dashed_beam(&vb, &pose, rel(src.cast::<f64>().map(|x| x + 0.5)), rel(dst), color);
+    let Some((hit, _)) = hit else { return };
+    let vb = source.call_object_method(mvc.multi_buffer_source_get_buffer, &[mvc.render_type_lines.raw]).unwrap().unwrap();
+    let min = hit.cast::<f64>() - camera_pos;
+    let (min, max) = (min.add_scalar(-0.002), min.add_scalar(1.002));
+    let args = [
+        pose_stack.raw(),
+        vb.raw,
+        d_raw(min.x),
+        d_raw(min.y),
+        d_raw(min.z),
+        d_raw(max.x),
+        d_raw(max.y),
+        d_raw(max.z),
+        f_raw(color.x),
+        f_raw(color.y),
+        f_raw(color.z),
+        f_raw(1.),
+    ];
+    mvc.level_renderer.with_jni(jni).call_static_void_method(mvc.level_renderer_render_line_box, &args).unwrap();
+    source.call_void_method(mvc.buffer_source_end_batch, &[mvc.render_type_lines.raw]).unwrap()
 }
@@ -1,6 +1,7 @@
 use crate::{
     asm::*,
     beams::{ClientState, ServerState, TrackedBlock},
+    config::{Balance, ClientConfig, ServerConfig},
     emitter_blocks::EmitterBlocks,
     emitter_items::EmitterItems,
     jvm::*,
@@ -8,6 +9,7 @@ use crate::{
     objs,
     registry::{add_greg_dyn_resource, EMITTER_ID, MOD_ID},
     ti,
+    upgrades::{Upgrade, Upgrades},
     util::{
         cleaner::Cleaner,
         client::{ClientDefs, Sprite},
@@ -45,6 +47,8 @@ pub struct GlobalObjs {
     pub gui_defs: GUIDefs,
     pub tile_defs: TileDefs,
     pub client_defs: Option<ClientDefs>,
+    pub client_config: Option<ClientConfig>,
+    pub server_config: ServerConfig,
     pub greg_reg_item_stub: MSig,
     pub greg_creative_tab_stub: MSig,
     pub greg_reinit_models_stub: MSig,
@@ -59,6 +63,7 @@ pub struct GlobalObjs {
 pub struct Tier {
     pub volt: i64,
     pub color: Vector3<f32>,
+    pub gt_color: Vector3<f32>,
     pub name: Arc<str>,
     pub has_emitter: bool,
     pub emitter_sprite: Option<Sprite>,
@@ -70,11 +75,14 @@ pub struct Tier {
 pub struct GlobalMtx {
     pub gmv: OnceCell<GregMV>,
     pub sheets_solid: OnceCell<GlobalRef<'static>>,
+    pub sheets_translucent: OnceCell<GlobalRef<'static>>,
     pub wire_sprite: Cell<Option<Sprite>>,
     pub emitter_items: OnceCell<EmitterItems>,
     pub emitter_blocks: OnceCell<EmitterBlocks>,
+    pub upgrades: OnceCell<Upgrades>,
     pub tier_lookup: RefCell<HashMap<Arc<str>, u8>>,
     pub tiers: RefCell<Vec<Tier>>,
+    pub balance: Cell<Balance>,
     pub server_state: RefCell<ServerState>,
     pub client_state: RefCell<ClientState>,
 }
@@ -121,9 +129,11 @@ impl GlobalObjs {
         Self {
             mtx: JMutex::new(av.jv.object.alloc_object().unwrap().new_global_ref().unwrap(), GlobalMtx::default()),
             client_defs: mv.client.fmap(|_| ClientDefs::init(&av, &namer, &cn, &mn)),
+            client_config: mv.client.fmap(|_| ClientConfig::new(av.ldr.jni, &fmv, &mv)),
+            server_config: ServerConfig::new(av.ldr.jni, &fmv),
             net_defs: NetworkDefs::init(&av, &namer, &cn, &mn, &mv, &fcn, &fmn),
             gui_defs: GUIDefs::init(&av, &cn, &mn, &fcn, &fmn, &namer),
-            tile_defs: TileDefs::init(&av, &cn, &mn, &namer),
+            tile_defs: TileDefs::init(&av, &cn, &mn, &fmn, &namer),
             cleaner: Cleaner::new(&av, &namer),
             gmn: GregMN::new(&cn, &gcn),
             namer,
@@ -171,9 +181,11 @@ fn greg_reg_item_stub(jni: &'static JNI, _: usize, name: usize) -> usize {
             let code = gmv.tier_fmt_names.get_object_elem(tier as _).unwrap().unwrap().chars().unwrap()[1];
             let fmt = mv.chat_fmt.with_jni(jni).call_static_object_method(mv.chat_fmt_from_code, &[code as _]).unwrap().unwrap();
             let color = fmt.get_object_field(mv.chat_fmt_color).unwrap().int_value(&av.jv).unwrap();
+            let color = vector![color >> 16, (color >> 8) & 255, color & 255].map(|x| x as f32 / 255.);
             tiers.push(Tier {
                 volt,
-                color: vector![color >> 16, (color >> 8) & 255, color & 255].map(|x| x as f32 / 255.),
+                color,
+                gt_color: color,
                 name,
                 has_emitter: false,
                 emitter_sprite: None,
@@ -182,6 +194,9 @@ fn greg_reg_item_stub(jni: &'static JNI, _: usize, name: usize) -> usize {
             })
         }
         drop(tier_volts);
+        if let Some(cfg) = &objs().client_config {
+            cfg.apply_tier_colors(jni, &mut tiers, &lookup)
+        }
         gmv
     });
     let &tier = lk.tier_lookup.borrow().get(tier).unwrap();
@@ -195,19 +210,37 @@ fn greg_creative_tab_stub(jni: &'static JNI, _: usize, item: usize) -> bool {
     !BorrowedRef::new(jni, &item).is_instance_of(objs().mtx.lock(jni).unwrap().emitter_items.get().unwrap().item.raw)
 }
 
+// Same transforms as vanilla's block/block.json
+const EMITTER_ITEM_DISPLAY: &str = r#""display":{
+"gui":{"rotation":[30,225,0],"translation":[0,0,0],"scale":[0.625,0.625,0.625]},
+"ground":{"rotation":[0,0,0],"translation":[0,3,0],"scale":[0.25,0.25,0.25]},
+"fixed":{"rotation":[0,0,0],"translation":[0,0,0],"scale":[0.5,0.5,0.5]},
+"thirdperson_righthand":{"rotation":[75,45,0],"translation":[0,2.5,0],"scale":[0.375,0.375,0.375]},
+"firstperson_righthand":{"rotation":[0,45,0],"translation":[0,0,0],"scale":[0.4,0.4,0.4]},
+"firstperson_lefthand":{"rotation":[0,225,0],"translation":[0,0,0],"scale":[0.4,0.4,0.4]}}"#;
+
 #[dyn_abi]
 fn greg_reinit_models_stub(jni: &JNI, _: usize) {
     let lk = objs().mtx.lock(jni).unwrap();
+    let Some(gmv) = lk.gmv.get() else { return };
     for tier in &*lk.tiers.borrow() {
         let true = tier.has_emitter else { continue };
         let id = format!("blockstates/{EMITTER_ID}_{}.json", tier.name);
         let json = format!("{{\"variants\":{{\"\":{{\"model\":\"gtceu:item/{}_emitter\"}}}}}}", tier.name);
-        add_greg_dyn_resource(jni, lk.gmv.get().unwrap(), id, &json)
+        add_greg_dyn_resource(jni, gmv, &cs(MOD_ID), id, &json);
+        let id = format!("models/item/{EMITTER_ID}_{}.json", tier.name);
+        let json =
+            format!("{{\"parent\":\"builtin/entity\",\"textures\":{{\"particle\":\"gtceu:item/{}_emitter\"}},{EMITTER_ITEM_DISPLAY}}}", tier.name);
+        add_greg_dyn_resource(jni, gmv, &cs(MOD_ID), id, &json)
+    }
+    for kind in Upgrade::ALL {
+        let json = format!("{{\"parent\":\"item/generated\",\"textures\":{{\"layer0\":\"{}\"}}}}", kind.texture());
+        add_greg_dyn_resource(jni, gmv, &cs(MOD_ID), format!("models/item/{}.json", kind.id()), &json)
     }
 }
 
 #[dyn_abi]
-fn mc_clear_level_stub(jni: &JNI, _: usize) { objs().mtx.lock(jni).unwrap().client_state.borrow_mut().beams.clear() }
+fn mc_clear_level_stub(jni: &JNI, _: usize) { objs().mtx.lock(jni).unwrap().client_state.borrow_mut().clear(jni) }
 
 #[dyn_abi]
 fn level_chunk_set_block_state_stub(jni: &JNI, _: usize, level: usize, pos: usize) {
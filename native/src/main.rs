@@ -25,6 +25,7 @@ macro_rules! dyn_abi {
 
 pub mod asm;
 mod beams;
+mod config;
 mod emitter_blocks;
 mod emitter_gui;
 mod emitter_items;
@@ -33,6 +34,7 @@ pub mod jvm;
 pub mod mapping_base;
 mod packets;
 mod registry;
+mod upgrades;
 mod util;
 
 extern crate alloc;
@@ -1,13 +1,15 @@
 use crate::{
     beams::{set_beam_dir, ClientBeam},
-    emitter_blocks::Emitter,
+    emitter_blocks::{clean_name, Access, Emitter, Owner},
     emitter_gui::EmitterMenu,
-    global::GlobalMtx,
+    global::{warn, GlobalMtx, GlobalObjs},
     jvm::*,
+    mapping_base::{cs, MBOptExt},
     objs,
-    util::{gui::GUIExt, strict_deserialize, tile::TileExt},
+    util::{geometry::GeomExt, gui::GUIExt, strict_deserialize, tile::TileExt, uuid_str, UtilExt},
 };
-use anyhow::{ensure, Context, Result};
+use alloc::{format, string::String, vec::Vec};
+use anyhow::{ensure, Context, Error, Result};
 use core::{
     f32::consts::{FRAC_PI_2, TAU},
     num::NonZeroUsize,
@@ -19,6 +21,35 @@ use serde::{Deserialize, Serialize};
 pub enum EmitterAction {
     SetAttitude { zenith: f32, azimuth: f32 },
     SetDisableTransfer(bool),
+    ScanReach,
+    SetAccess(Access),
+    SetName(String),
+}
+
+const MAX_PACKETS_PER_TICK: u32 = 32;
+const SCAN_COOLDOWN: u8 = 10;
+const LOG_INTERVAL: u8 = 20;
+
+#[derive(Default)]
+pub struct PacketLimiter {
+    attitude: Option<(i32, f32, f32)>,
+    scan: Option<i32>,
+    scan_cooldown: u8,
+    this_tick: u32,
+    dropped: u32,
+    rejected: u32,
+    last_rejection: Option<Error>,
+    ticks: u8,
+}
+
+impl PacketLimiter {
+    fn is_idle(&self) -> bool { self.attitude.is_none() && self.scan.is_none() && self.scan_cooldown == 0 && self.dropped == 0 && self.rejected == 0 }
+}
+
+fn player_id<'a>(player: &impl JRef<'a>) -> u128 { player.call_object_method(objs().mv.entity_get_uuid, &[]).unwrap().unwrap().read_uuid() }
+
+fn with_limiter<R>(lk: &GlobalMtx, id: u128, f: impl FnOnce(&mut PacketLimiter) -> R) -> R {
+    f(lk.server_state.borrow_mut().packet_limiters.entry(id).or_default())
 }
 
 #[derive(Serialize, Deserialize)]
@@ -31,41 +62,139 @@ pub struct C2S {
 pub enum S2C {
     SetBeam { id: NonZeroUsize, data: ClientBeam },
     DelBeam { id: NonZeroUsize },
+    MenuData { menu_id: i32, data: Vec<u8> },
 }
 
-pub fn handle_s2c(lk: &GlobalMtx, data: &[u8]) -> Result<()> {
+pub fn handle_s2c(lk: &GlobalMtx, jni: &'static JNI, data: &[u8]) -> Result<()> {
     let data: S2C = strict_deserialize(data)?;
     Ok(match data {
-        S2C::SetBeam { id, data } => {
-            lk.client_state.borrow_mut().beams.insert(id, data);
-        }
-        S2C::DelBeam { id } => {
-            lk.client_state.borrow_mut().beams.remove(&id);
-        }
+        S2C::SetBeam { id, data } => lk.client_state.borrow_mut().set_beam(jni, id, data),
+        S2C::DelBeam { id } => lk.client_state.borrow_mut().del_beam(jni, id),
+        S2C::MenuData { menu_id, data } => receive_menu_data(lk, jni, menu_id, &data)?,
     })
 }
 
+fn receive_menu_data(lk: &GlobalMtx, jni: &JNI, menu_id: i32, data: &[u8]) -> Result<()> {
+    let GlobalObjs { mv, gui_defs, .. } = objs();
+    let mvc = mv.client.uref();
+    let player = mvc.mc_inst.with_jni(jni).get_object_field(mvc.mc_player).context("no player")?;
+    let menu = player.player_container_menu().context("no menu")?;
+    if menu.menu_id() != menu_id || !menu.is_instance_of(gui_defs.menu.cls.cls.raw) {
+        return Ok(());
+    }
+    gui_defs.menu.read(lk, menu.borrow()).receive_data(lk, data)
+}
+
 pub fn handle_c2s(lk: &GlobalMtx, data: &[u8], player: BorrowedRef<'static, '_>) -> Result<()> {
-    let gui_defs = &objs().gui_defs;
     let C2S { menu_id, action } = strict_deserialize(data)?;
+    let id = player_id(&player);
+    let over_limit = with_limiter(lk, id, |x| {
+        x.this_tick += 1;
+        x.dropped += (x.this_tick > MAX_PACKETS_PER_TICK) as u32;
+        x.this_tick > MAX_PACKETS_PER_TICK
+    });
+    if over_limit {
+        return Ok(());
+    }
+    if let Err(e) = apply_action(lk, id, menu_id, action, player) {
+        with_limiter(lk, id, |x| (x.rejected, x.last_rejection) = (x.rejected + 1, Some(e)))
+    }
+    Ok(())
+}
+
+fn apply_action(lk: &GlobalMtx, id: u128, menu_id: i32, action: EmitterAction, player: BorrowedRef<'static, '_>) -> Result<()> {
+    let gui_defs = &objs().gui_defs;
     let menu = player.player_container_menu().context("no menu")?;
-    ensure!(menu.menu_id() == menu_id);
-    ensure!(menu.is_instance_of(gui_defs.menu.cls.cls.raw));
+    ensure!(menu.menu_id() == menu_id, "stale menu");
+    ensure!(menu.is_instance_of(gui_defs.menu.cls.cls.raw), "not a native menu");
     let menu: &EmitterMenu = gui_defs.menu.read(lk, menu.borrow()).any().downcast_ref().context("wrong menu")?;
     let tile = menu.tile.with_jni(player.jni).new_local_ref()?;
     let level = tile.tile_level().context("dead tile")?;
     let emitter = lk.read_tile::<Emitter>(tile.borrow());
+    ensure!(emitter.can_access(&player, id), "no access");
+    if let EmitterAction::SetAccess(_) = action {
+        ensure!(emitter.can_manage(&player, id), "not the owner")
+    }
     let mut data = emitter.data.borrow_mut();
     match action {
         EmitterAction::SetAttitude { zenith, azimuth } => {
-            data.zenith = if zenith.is_finite() { zenith.clamp(0., FRAC_PI_2) } else { 0. };
-            data.azimuth = if azimuth.is_finite() { azimuth.rem_euclid(&TAU) } else { 0. };
-            if let Some(beam_id) = emitter.beam_id.get() {
-                set_beam_dir(lk, level.jni, beam_id, data.compute_dir())
-            }
+            let zenith = if zenith.is_finite() { zenith.clamp(0., FRAC_PI_2) } else { 0. };
+            let azimuth = if azimuth.is_finite() { azimuth.rem_euclid(&TAU) } else { 0. };
+            return Ok(with_limiter(lk, id, |x| x.attitude = Some((menu_id, zenith, azimuth))));
         }
         EmitterAction::SetDisableTransfer(x) => data.disable_transfer = x,
+        EmitterAction::ScanReach => return Ok(with_limiter(lk, id, |x| x.scan = Some(menu_id))),
+        EmitterAction::SetAccess(x) => {
+            data.owner.get_or_insert_with(|| Owner::of(&player));
+            data.access = x
+        }
+        EmitterAction::SetName(x) => data.name = clean_name(&x),
     }
     tile.tile_mark_for_save();
     Ok(level.level_mark_for_broadcast(&tile.tile_pos()))
 }
+
+pub fn tick_menu(lk: &GlobalMtx, menu: &EmitterMenu, player: BorrowedRef<'static, '_>) {
+    let Some(menu_id) = player.player_container_menu().map(|x| x.menu_id()) else { return };
+    let id = player_id(&player);
+    let Some((attitude, scan)) = lk.server_state.borrow_mut().packet_limiters.get_mut(&id).map(|x| {
+        let attitude = x.attitude.take_if(|x| x.0 == menu_id);
+        let scan = x.scan == Some(menu_id) && x.scan_cooldown == 0;
+        if scan {
+            (x.scan, x.scan_cooldown) = (None, SCAN_COOLDOWN)
+        }
+        (attitude, scan)
+    }) else {
+        return;
+    };
+    let Ok(tile) = menu.tile.with_jni(player.jni).new_local_ref() else { return };
+    let Some(level) = tile.tile_level() else { return };
+    let emitter = lk.read_tile::<Emitter>(tile.borrow());
+    if let Some((_, zenith, azimuth)) = attitude {
+        let mut data = emitter.data.borrow_mut();
+        (data.zenith, data.azimuth) = (zenith, azimuth);
+        if let Some(beam_id) = emitter.beam_id.get() {
+            set_beam_dir(lk, level.jni, beam_id, data.compute_dir())
+        }
+        drop(data);
+        tile.tile_mark_for_save();
+        level.level_mark_for_broadcast(&tile.tile_pos())
+    }
+    if scan {
+        *menu.reach.borrow_mut() = Some(emitter.reach(lk, &level, tile.tile_pos().read_vec3i()))
+    }
+}
+
+pub fn tick_limiter(lk: &GlobalMtx, player: BorrowedRef<'static, '_>) {
+    if lk.server_state.borrow().packet_limiters.is_empty() {
+        return;
+    }
+    let id = player_id(&player);
+    let mut srv = lk.server_state.borrow_mut();
+    let Some(limiter) = srv.packet_limiters.get_mut(&id) else { return };
+    limiter.this_tick = 0;
+    limiter.attitude = None;
+    match limiter.scan_cooldown {
+        0 => limiter.scan = None,
+        _ => limiter.scan_cooldown -= 1,
+    }
+    limiter.ticks += 1;
+    if limiter.ticks == LOG_INTERVAL {
+        limiter.ticks = 0;
+        if limiter.dropped > 0 || limiter.rejected > 0 {
+            let reason = limiter.last_rejection.take().map_or(<_>::default(), |e| format!("{e:#}"));
+            let (dropped, rejected) = (limiter.dropped, limiter.rejected);
+            (limiter.dropped, limiter.rejected) = (0, 0);
+            drop(srv);
+            let Owner { name, .. } = Owner::of(&player);
+            let msg = format!(
+                "Limited emitter packets: player={name} uuid={} dropped={dropped} rejected={rejected} last_reason=\"{reason}\" ticks={LOG_INTERVAL}",
+                uuid_str(id)
+            );
+            return warn(player.jni, &cs(msg));
+        }
+    }
+    if limiter.is_idle() {
+        srv.packet_limiters.remove(&id);
+    }
+}
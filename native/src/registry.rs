@@ -1,8 +1,11 @@
+use crate::global::Tier;
+use crate::packets::tick_limiter;
 use crate::util::client::ClientExt;
 use crate::util::geometry::GeomExt;
+use crate::util::gui::tick_open_menu;
 use crate::util::ClassBuilder;
 use crate::util::{client::Sprite, mapping::GregMV};
-use crate::{asm::*, emitter_blocks::EmitterBlocks, global::GlobalObjs, jvm::*, mapping_base::*, objs, ti};
+use crate::{asm::*, emitter_blocks::EmitterBlocks, global::GlobalObjs, jvm::*, mapping_base::*, objs, ti, upgrades::Upgrades};
 use alloc::boxed::Box;
 use alloc::{format, vec::Vec};
 use core::ffi::{c_char, CStr};
@@ -10,7 +13,7 @@ use macros::dyn_abi;
 use nalgebra::Point2;
 
 pub const MOD_ID: &str = "greg_emitters";
-pub const PROTOCOL_VERSION: &CStr = c"2";
+pub const PROTOCOL_VERSION: &CStr = c"6";
 pub const EMITTER_ID: &str = "emitter";
 
 pub fn init() {
@@ -29,11 +32,21 @@ pub fn init() {
     add_forge_listener(&fmv.com_evt_bus, fcn.chunk_unwatch_evt.sig.to_bytes(), on_chunk_unwatch_dyn());
     add_forge_listener(&fmv.com_evt_bus, fcn.chunk_load_evt.sig.to_bytes(), on_chunk_load_or_unload_dyn());
     add_forge_listener(&fmv.com_evt_bus, fcn.chunk_unload_evt.sig.to_bytes(), on_chunk_load_or_unload_dyn());
+    add_forge_listener(&fmv.com_evt_bus, fcn.player_tick_evt.sig.to_bytes(), on_player_tick_dyn());
+    add_forge_listener(&fmv.mod_evt_bus, fcn.config_loading_evt.sig.to_bytes(), on_server_config_dyn());
+    add_forge_listener(&fmv.mod_evt_bus, fcn.config_reloading_evt.sig.to_bytes(), on_server_config_dyn());
     if fmv.client.is_some() {
         add_forge_listener(&fmv.mod_evt_bus, fcn.atlas_evt.sig.to_bytes(), on_forge_atlas_dyn());
         add_forge_listener(&fmv.mod_evt_bus, fcn.renderers_evt.sig.to_bytes(), on_forge_renderers_dyn());
+        add_forge_listener(&fmv.mod_evt_bus, fcn.client_exts_evt.sig.to_bytes(), on_reg_client_exts_dyn());
         add_forge_listener(&fmv.mod_evt_bus, fcn.fml_client_setup_evt.sig.to_bytes(), on_forge_client_setup_dyn());
-        add_forge_listener(&fmv.com_evt_bus, fcn.render_lvl_stg_evt.sig.to_bytes(), on_level_render_dyn())
+        add_forge_listener(&fmv.mod_evt_bus, fcn.key_mappings_evt.sig.to_bytes(), on_reg_key_mappings_dyn());
+        add_forge_listener(&fmv.mod_evt_bus, fcn.models_reg_evt.sig.to_bytes(), on_reg_models_dyn());
+        add_forge_listener(&fmv.mod_evt_bus, fcn.models_baked_evt.sig.to_bytes(), on_models_baked_dyn());
+        add_forge_listener(&fmv.mod_evt_bus, fcn.config_loading_evt.sig.to_bytes(), on_client_config_dyn());
+        add_forge_listener(&fmv.mod_evt_bus, fcn.config_reloading_evt.sig.to_bytes(), on_client_config_dyn());
+        add_forge_listener(&fmv.com_evt_bus, fcn.render_lvl_stg_evt.sig.to_bytes(), on_level_render_dyn());
+        add_forge_listener(&fmv.com_evt_bus, fcn.client_tick_evt.sig.to_bytes(), on_client_tick_dyn())
     }
 }
 
@@ -53,9 +66,9 @@ pub fn make_resource_loc<'a>(jni: &'a JNI, ns: &CStr, id: &CStr) -> LocalRef<'a>
     mv.resource_loc.with_jni(jni).new_object(mv.resource_loc_init, &[ns.raw, id.raw]).unwrap()
 }
 
-pub fn add_greg_dyn_resource(jni: &JNI, gmv: &GregMV, id: impl Into<Vec<u8>>, json: &str) {
+pub fn add_greg_dyn_resource(jni: &JNI, gmv: &GregMV, ns: &CStr, id: impl Into<Vec<u8>>, json: &str) {
     let data = gmv.dyn_resource_pack_data.with_jni(jni);
-    let key = make_resource_loc(jni, &cs(MOD_ID), &cs(id));
+    let key = make_resource_loc(jni, ns, &cs(id));
     let ba = jni.new_byte_array(json.len() as _).unwrap();
     ba.write_byte_array(json.as_bytes(), 0).unwrap();
     data.map_put(&objs().av.jv, key.raw, ba.raw).unwrap();
@@ -80,6 +93,8 @@ fn on_forge_reg(jni: &'static JNI, _: usize, evt: usize) {
         register(&evt, EMITTER_ID, lk.emitter_blocks.get().unwrap().tile_type.raw)
     } else if key.equals(&av.jv, mv.reg_key_menu.raw).unwrap() {
         register(&evt, EMITTER_ID, lk.emitter_blocks.get().unwrap().menu_type.raw)
+    } else if key.equals(&av.jv, mv.reg_key_item.raw).unwrap() {
+        lk.upgrades.get_or_init(|| Upgrades::init(jni, &evt));
     }
 }
 
@@ -121,6 +136,17 @@ fn on_chunk_unwatch(jni: &JNI, _: usize, evt: usize) {
     crate::beams::on_chunk_unwatch(&player, pos)
 }
 
+// Unlike the menu's own stillValid, this runs exactly once per tick and never from packet handling.
+#[dyn_abi]
+fn on_player_tick(jni: &'static JNI, _: usize, evt: usize) {
+    let GlobalObjs { mv, fmv, mtx, .. } = objs();
+    let player = BorrowedRef::new(jni, &evt).call_object_method(fmv.player_evt_get_entity, &[]).unwrap().unwrap();
+    let true = player.is_instance_of(mv.server_player.raw) else { return };
+    let lk = mtx.lock(jni).unwrap();
+    tick_open_menu(&lk, player.borrow());
+    tick_limiter(&lk, player.borrow())
+}
+
 #[dyn_abi]
 fn on_chunk_load_or_unload(jni: &'static JNI, _: usize, evt: usize) {
     let GlobalObjs { mv, fmv, .. } = objs();
@@ -138,7 +164,8 @@ fn on_level_render(jni: &JNI, _: usize, evt: usize) {
     let evt = BorrowedRef::new(jni, &evt);
     let stg = evt.get_object_field(fmvc.render_lvl_stg_evt_stage).unwrap();
     let true = stg.is_same_object(fmvc.render_lvl_stg_after_tiles.raw) else { return };
-    let pose = evt.get_object_field(fmvc.render_lvl_stg_evt_pose).unwrap().last_pose();
+    let pose_stack = evt.get_object_field(fmvc.render_lvl_stg_evt_pose).unwrap();
+    let pose = pose_stack.last_pose();
     let camera = evt.get_object_field(fmvc.render_lvl_stg_evt_camera).unwrap();
     let camera_pos = camera.get_object_field(mvc.camera_pos).unwrap().read_vec3d().cast();
     let tick = evt.get_int_field(fmvc.render_lvl_stg_evt_tick);
@@ -150,12 +177,43 @@ fn on_level_render(jni: &JNI, _: usize, evt: usize) {
     let vb = source.call_object_method(mvc.multi_buffer_source_get_buffer, &[mvc.render_type_lightning.raw]).unwrap().unwrap();
     let lk = objs().mtx.lock(jni).unwrap();
     let tiers = lk.tiers.borrow();
-    for beam in objs().mtx.lock(jni).unwrap().client_state.borrow().beams.values() {
-        beam.render(&*tiers, &vb, &pose, camera_pos, tick, sub_tick)
+    let state = lk.client_state.borrow();
+    if !state.hide_beams {
+        for (beam, dst) in state.beams.values() {
+            beam.render(&*tiers, &state.beam_style, &vb, &pose, camera_pos, dst.get(tick as f32 + sub_tick), tick, sub_tick)
+        }
     }
+    drop(state);
+    crate::emitter_items::render_placement_preview(&lk, &pose_stack, &source, camera_pos);
     source.call_void_method(mvc.buffer_source_end_batch, &[mvc.render_type_lightning.raw]).unwrap()
 }
 
+#[dyn_abi]
+fn on_client_tick(jni: &'static JNI, _: usize, _: usize) {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let mut state = lk.client_state.borrow_mut();
+    state.hide_beams ^= objs().client_config.uref().poll_hide_beams(jni);
+    state.tick(jni)
+}
+
+#[dyn_abi]
+fn on_reg_key_mappings(jni: &JNI, _: usize, evt: usize) {
+    let evt = BorrowedRef::new(jni, &evt);
+    let key = objs().client_config.uref().hide_beams_key.raw;
+    evt.call_void_method(objs().fmv.client.uref().key_mappings_evt_reg, &[key]).unwrap()
+}
+
+#[dyn_abi]
+fn on_server_config(jni: &JNI, _: usize, _: usize) { objs().mtx.lock(jni).unwrap().balance.set(objs().server_config.balance(jni)) }
+
+#[dyn_abi]
+fn on_client_config(jni: &JNI, _: usize, _: usize) {
+    let cfg = objs().client_config.uref();
+    let lk = objs().mtx.lock(jni).unwrap();
+    lk.client_state.borrow_mut().beam_style = cfg.beam_style(jni);
+    cfg.apply_tier_colors(jni, &mut lk.tiers.borrow_mut(), &lk.tier_lookup.borrow())
+}
+
 #[dyn_abi]
 fn on_forge_renderers(jni: &JNI, _: usize, evt: usize) {
     let evt = BorrowedRef::new(jni, &evt);
@@ -166,6 +224,50 @@ fn on_forge_renderers(jni: &JNI, _: usize, evt: usize) {
     evt.call_void_method(fmvc.renderers_evt_reg, &[defs.tile_type.raw, provider]).unwrap()
 }
 
+#[dyn_abi]
+fn on_reg_client_exts(jni: &JNI, _: usize, evt: usize) {
+    let GlobalObjs { mv, fmv, mtx, .. } = objs();
+    let evt = BorrowedRef::new(jni, &evt);
+    let lk = mtx.lock(jni).unwrap();
+    let Some(defs) = lk.emitter_items.get() else { return };
+    let renderer = defs.renderer.uref();
+    for tier in &*lk.tiers.borrow() {
+        let Some(item) = tier.emitter_item.get() else { continue };
+        let items = mv.item.with_jni(jni).new_object_array(1, item.raw).unwrap();
+        evt.call_void_method(fmv.client.uref().client_exts_evt_reg_item, &[renderer.raw, items.raw]).unwrap()
+    }
+}
+
+fn emitter_item_model<'a>(jni: &'a JNI, tier: &Tier) -> LocalRef<'a> {
+    let mvc = objs().mv.client.uref();
+    let loc = make_resource_loc(jni, &cs(MOD_ID), &cs(format!("item/{EMITTER_ID}_{}", tier.name)));
+    mvc.model_res_loc.with_jni(jni).call_static_object_method(mvc.model_res_loc_standalone, &[loc.raw]).unwrap().unwrap()
+}
+
+#[dyn_abi]
+fn on_reg_models(jni: &JNI, _: usize, evt: usize) {
+    let evt = BorrowedRef::new(jni, &evt);
+    let lk = objs().mtx.lock(jni).unwrap();
+    for tier in lk.tiers.borrow().iter().filter(|x| x.has_emitter) {
+        evt.call_void_method(objs().fmv.client.uref().models_reg_evt_reg, &[emitter_item_model(jni, tier).raw]).unwrap()
+    }
+}
+
+// Emitters are GT's emitter items, so their model is swapped here rather than replaced in GT's namespace.
+#[dyn_abi]
+fn on_models_baked(jni: &JNI, _: usize, evt: usize) {
+    let GlobalObjs { av, mv, fmv, mtx, .. } = objs();
+    let (mvc, fmvc) = (mv.client.uref(), fmv.client.uref());
+    let models = BorrowedRef::new(jni, &evt).call_object_method(fmvc.models_baked_evt_get_models, &[]).unwrap().unwrap();
+    let lk = mtx.lock(jni).unwrap();
+    for tier in lk.tiers.borrow().iter().filter(|x| x.has_emitter) {
+        let Some(model) = models.call_object_method(fmvc.map_get, &[emitter_item_model(jni, tier).raw]).unwrap() else { continue };
+        let item = make_resource_loc(jni, c"gtceu", &cs(format!("{}_emitter", tier.name)));
+        let item = mvc.model_res_loc.with_jni(jni).call_static_object_method(mvc.model_res_loc_inventory, &[item.raw]).unwrap().unwrap();
+        models.map_put(&av.jv, item.raw, model.raw).unwrap();
+    }
+}
+
 #[dyn_abi]
 fn on_forge_atlas(jni: &'static JNI, _: usize, evt: usize) {
     let evt = BorrowedRef::new(jni, &evt);
@@ -175,11 +277,14 @@ fn on_forge_atlas(jni: &'static JNI, _: usize, evt: usize) {
     let loc = atlas.call_object_method(mvc.atlas_loc, &[]).unwrap().unwrap();
     if loc.equals(&av.jv, mvc.atlas_loc_blocks.raw).unwrap() {
         let lk = mtx.lock(jni).unwrap();
-        lk.sheets_solid.get_or_init(|| {
+        if lk.sheets_solid.get().is_none() {
             let sheets = av.ldr.with_jni(atlas.jni()).load_class(&av.jv, &cn.sheets.dot).unwrap();
-            let sheets_solid = mn.sheets_solid.get_static_method_id(&sheets).unwrap();
-            sheets.call_static_object_method(sheets_solid, &[]).unwrap().unwrap().new_global_ref().unwrap()
-        });
+            let get = |mn: &MSig| {
+                sheets.call_static_object_method(mn.get_static_method_id(&sheets).unwrap(), &[]).unwrap().unwrap().new_global_ref().unwrap()
+            };
+            lk.sheets_solid.set(get(&mn.sheets_solid)).ok().unwrap();
+            lk.sheets_translucent.set(get(&mn.sheets_translucent)).ok().unwrap()
+        }
         lk.wire_sprite.set(Some(Sprite::new(&atlas, c"gtceu", c"block/cable/wire")));
         for tier in &mut *lk.tiers.borrow_mut() {
             tier.emitter_sprite = Some(Sprite::new(&atlas, c"gtceu", &cs(format!("item/{}_emitter", tier.name))))
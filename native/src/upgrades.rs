@@ -0,0 +1,191 @@
+use crate::{
+    emitter_blocks::Emitter,
+    global::{GlobalMtx, GlobalObjs},
+    jvm::*,
+    objs,
+    registry::register,
+    util::{cleaner::Cleanable, ClassBuilder, ThinWrapper},
+};
+use alloc::sync::Arc;
+use core::cell::OnceCell;
+use macros::dyn_abi;
+use serde::{Deserialize, Serialize};
+
+pub const UPGRADE_SLOTS: usize = 4;
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Upgrade {
+    Amperage,
+    Efficiency,
+}
+
+impl Upgrade {
+    pub const ALL: [Self; 2] = [Self::Amperage, Self::Efficiency];
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Amperage => "amperage_upgrade",
+            Self::Efficiency => "efficiency_upgrade",
+        }
+    }
+
+    pub fn from_id(id: &[u8]) -> Option<Self> { Self::ALL.into_iter().find(|x| x.id().as_bytes() == id) }
+
+    pub fn max_count(self) -> usize {
+        match self {
+            Self::Amperage => 3,
+            Self::Efficiency => 1,
+        }
+    }
+
+    pub fn texture(self) -> &'static str {
+        match self {
+            Self::Amperage => "gtceu:item/lv_voltage_coil",
+            Self::Efficiency => "gtceu:item/lv_sensor",
+        }
+    }
+}
+
+pub struct Upgrades {
+    items: [GlobalRef<'static>; 2],
+    handler: ThinWrapper<ItemHandler>,
+}
+
+pub struct ItemHandler {
+    pub tile: OnceCell<WeakGlobalRef<'static>>,
+}
+
+impl Cleanable for ItemHandler {
+    fn free(self: Arc<Self>, jni: &JNI) { Arc::into_inner(self).unwrap().tile.into_inner().unwrap().replace_jni(jni); }
+}
+
+impl Upgrades {
+    pub fn init(jni: &'static JNI, reg_evt: &impl JRef<'static>) -> Self {
+        let GlobalObjs { mv, fcn, fmn, .. } = objs();
+        let handler = ClassBuilder::new_2(jni, c"java/lang/Object")
+            .interfaces([&*fcn.item_handler.slash])
+            .native_2(&fmn.item_handler_get_slots, get_slots_dyn())
+            .native_2(&fmn.item_handler_get_stack, get_stack_dyn())
+            .native_2(&fmn.item_handler_set_stack, set_stack_dyn())
+            .native_2(&fmn.item_handler_insert, insert_dyn())
+            .native_2(&fmn.item_handler_extract, extract_dyn())
+            .native_2(&fmn.item_handler_get_slot_limit, get_slot_limit_dyn())
+            .native_2(&fmn.item_handler_is_valid, is_valid_dyn())
+            .define_thin()
+            .wrap::<ItemHandler>();
+        let items = Upgrade::ALL.map(|kind| {
+            let mut props = mv.item_props.with_jni(jni).new_object(mv.item_props_init, &[]).unwrap();
+            props = props.call_object_method(mv.item_props_stacks_to, &[1]).unwrap().unwrap();
+            let item = mv.item.with_jni(jni).new_object(mv.item_init, &[props.raw]).unwrap();
+            register(reg_evt, kind.id(), item.raw);
+            item.new_global_ref().unwrap()
+        });
+        Self { items, handler }
+    }
+
+    pub fn new_handler<'a>(&self, jni: &'a JNI, handler: Arc<ItemHandler>) -> LocalRef<'a> { self.handler.new_obj(jni, handler) }
+    pub fn new_stack<'a>(&self, jni: &'a JNI, kind: Upgrade) -> LocalRef<'a> {
+        let mv = &objs().mv;
+        mv.item_stack.with_jni(jni).new_object(mv.item_stack_init, &[self.items[kind as usize].raw, 1]).unwrap()
+    }
+
+    pub fn kind_of<'a>(&self, stack: &impl JRef<'a>) -> Option<Upgrade> {
+        let mv = &objs().mv;
+        let false = stack.call_bool_method(mv.item_stack_is_empty, &[]).unwrap() else { return None };
+        let item = stack.call_object_method(mv.item_stack_get_item, &[]).unwrap().unwrap();
+        Upgrade::ALL.into_iter().find(|&kind| item.is_same_object(self.items[kind as usize].raw))
+    }
+}
+
+//////////////////////////////////
+// Item Handler Implementations //
+//////////////////////////////////
+
+fn handler_tile<'a>(lk: &GlobalMtx, this: BorrowedRef<'a, '_>) -> Option<LocalRef<'a>> {
+    lk.upgrades.get().unwrap().handler.read(lk, this).tile.get()?.with_jni(&this.jni).new_local_ref().ok()
+}
+
+fn accepted_kind<'a>(lk: &GlobalMtx, emitter: &Emitter, slot: i32, stack: &impl JRef<'a>) -> Option<Upgrade> {
+    let data = emitter.data.borrow();
+    data.upgrades.get(slot as usize)?;
+    let kind = lk.upgrades.get().unwrap().kind_of(stack)?;
+    let others = data.upgrades.iter().enumerate().filter(|&(i, &x)| i != slot as usize && x == Some(kind)).count();
+    (others < kind.max_count()).then_some(kind)
+}
+
+#[dyn_abi]
+fn get_slots(_: &JNI, _this: usize) -> i32 { UPGRADE_SLOTS as _ }
+
+#[dyn_abi]
+fn get_slot_limit(_: &JNI, _this: usize, _slot: i32) -> i32 { 1 }
+
+#[dyn_abi]
+fn get_stack(jni: &JNI, this: usize, slot: i32) -> usize {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let kind: Option<_> = try {
+        let tile = handler_tile(&lk, BorrowedRef::new(jni, &this))?;
+        let kind = *lk.read_tile::<Emitter>(tile.borrow()).data.borrow().upgrades.get(slot as usize)?;
+        kind?
+    };
+    match kind {
+        Some(kind) => lk.upgrades.get().unwrap().new_stack(jni, kind).into_raw(),
+        None => objs().mv.item_stack_empty.raw,
+    }
+}
+
+#[dyn_abi]
+fn set_stack(jni: &JNI, this: usize, slot: i32, stack: usize) {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let Some(tile) = handler_tile(&lk, BorrowedRef::new(jni, &this)) else { return };
+    let emitter = lk.read_tile::<Emitter>(tile.borrow());
+    let stack = BorrowedRef::new(jni, &stack);
+    let kind = match accepted_kind(&lk, emitter, slot, &stack) {
+        Some(kind) => Some(kind),
+        None if stack.call_bool_method(objs().mv.item_stack_is_empty, &[]).unwrap() => None,
+        None => return,
+    };
+    emitter.set_upgrade(&lk, &tile, slot as _, kind)
+}
+
+#[dyn_abi]
+fn insert(jni: &JNI, this: usize, slot: i32, stack: usize, simulate: bool) -> usize {
+    let mv = &objs().mv;
+    let lk = objs().mtx.lock(jni).unwrap();
+    let Some(tile) = handler_tile(&lk, BorrowedRef::new(jni, &this)) else { return stack };
+    let emitter = lk.read_tile::<Emitter>(tile.borrow());
+    let stack = BorrowedRef::new(jni, &stack);
+    let Some(kind) = accepted_kind(&lk, emitter, slot, &stack) else { return stack.raw };
+    if emitter.data.borrow().upgrades[slot as usize].is_some() {
+        return stack.raw;
+    }
+    if !simulate {
+        emitter.set_upgrade(&lk, &tile, slot as _, Some(kind))
+    }
+    let count = stack.call_int_method(mv.item_stack_get_count, &[]).unwrap();
+    stack.call_object_method(mv.item_stack_copy_with_count, &[(count - 1) as _]).unwrap().unwrap().into_raw()
+}
+
+#[dyn_abi]
+fn extract(jni: &JNI, this: usize, slot: i32, amount: i32, simulate: bool) -> usize {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let kind: Option<_> = try {
+        let tile = handler_tile(&lk, BorrowedRef::new(jni, &this)).filter(|_| amount > 0)?;
+        let emitter = lk.read_tile::<Emitter>(tile.borrow());
+        let kind = (*emitter.data.borrow().upgrades.get(slot as usize)?)?;
+        if !simulate {
+            emitter.set_upgrade(&lk, &tile, slot as _, None)
+        }
+        kind
+    };
+    match kind {
+        Some(kind) => lk.upgrades.get().unwrap().new_stack(jni, kind).into_raw(),
+        None => objs().mv.item_stack_empty.raw,
+    }
+}
+
+#[dyn_abi]
+fn is_valid(jni: &JNI, this: usize, slot: i32, stack: usize) -> bool {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let Some(tile) = handler_tile(&lk, BorrowedRef::new(jni, &this)) else { return false };
+    let result = accepted_kind(&lk, lk.read_tile::<Emitter>(tile.borrow()), slot, &BorrowedRef::new(jni, &stack)).is_some();
+    result
+}
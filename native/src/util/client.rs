@@ -7,10 +7,13 @@ use super::{
 };
 use crate::{global::GlobalMtx, jvm::*, mapping_base::*, objs, registry::make_resource_loc};
 use alloc::sync::Arc;
-use core::{ffi::CStr, mem::MaybeUninit};
+use core::{cell::Cell, ffi::CStr, mem::MaybeUninit};
 use macros::dyn_abi;
 use nalgebra::{point, vector, Affine3, ArrayStorage, Matrix4, Point2, Point3, Vector3};
 
+pub const FULL_BRIGHT: i32 = 0xF000F0;
+pub const NO_OVERLAY: i32 = 0xA0000;
+
 impl<'a, T: JRef<'a>> ClientExt<'a> for T {}
 pub trait ClientExt<'a>: JRef<'a> {
     fn font_width(&self, formatted: &impl JRef<'a>) -> i32 { self.call_int_method(objs().mv.client.uref().font_width, &[formatted.raw()]).unwrap() }
@@ -64,12 +67,90 @@ pub trait ClientExt<'a>: JRef<'a> {
     }
 }
 
+pub fn write_pose<'a>(jni: &'a JNI, pose: &Affine3<f32>) -> LocalRef<'a> {
+    let mvc = objs().mv.client.uref();
+    let matrix = mvc.matrix4f.with_jni(jni).new_object(mvc.matrix4f_init, &[]).unwrap();
+    matrix.call_object_method(mvc.matrix4f_write, &[pose.matrix().as_ptr() as _]).unwrap();
+    matrix
+}
+
 pub fn play_btn_click_sound(jni: &JNI) {
     let mvc = objs().mv.client.uref();
     let args = [objs().mv.sound_evts_ui_btn_click.raw, f_raw(1.)];
     let inst = mvc.simple_sound_inst.with_jni(jni).call_static_object_method(mvc.simple_sound_inst_for_ui_holder, &args).unwrap().unwrap();
-    let mgr = mvc.mc_inst.with_jni(jni).call_object_method(mvc.mc_get_sound_mgr, &[]).unwrap().unwrap();
-    mgr.call_void_method(mvc.sound_mgr_play, &[inst.raw]).unwrap()
+    play_sound(&inst)
+}
+
+pub fn new_block_sound<'a>(jni: &'a JNI, id: &CStr, pos: Point3<f64>, volume: f32, pitch: f32, looping: bool) -> LocalRef<'a> {
+    let mvc = objs().mv.client.uref();
+    let loc = make_resource_loc(jni, c"minecraft", id);
+    let random = mvc.sound_inst.with_jni(jni).call_static_object_method(mvc.sound_inst_create_random, &[]).unwrap().unwrap();
+    let args = [
+        loc.raw,
+        mvc.sound_source_blocks.raw,
+        f_raw(volume),
+        f_raw(pitch),
+        random.raw,
+        looping as _,
+        0,
+        mvc.sound_attenuation_linear.raw,
+        d_raw(pos.x),
+        d_raw(pos.y),
+        d_raw(pos.z),
+        false as _,
+    ];
+    mvc.simple_sound_inst.with_jni(jni).new_object(mvc.simple_sound_inst_init, &args).unwrap()
+}
+
+pub fn play_sound<'a>(inst: &impl JRef<'a>) {
+    let mvc = objs().mv.client.uref();
+    let mgr = mvc.mc_inst.with_jni(inst.jni()).call_object_method(mvc.mc_get_sound_mgr, &[]).unwrap().unwrap();
+    mgr.call_void_method(mvc.sound_mgr_play, &[inst.raw()]).unwrap()
+}
+
+pub fn stop_sound<'a>(inst: &impl JRef<'a>) {
+    let mvc = objs().mv.client.uref();
+    let mgr = mvc.mc_inst.with_jni(inst.jni()).call_object_method(mvc.mc_get_sound_mgr, &[]).unwrap().unwrap();
+    mgr.call_void_method(mvc.sound_mgr_stop, &[inst.raw()]).unwrap()
+}
+
+pub fn client_ticks(jni: &JNI) -> i32 {
+    let mvc = objs().mv.client.uref();
+    mvc.mc_inst.with_jni(jni).get_object_field(mvc.mc_level_renderer).unwrap().get_int_field(mvc.level_renderer_ticks)
+}
+
+pub trait Interpolate: Copy {
+    fn interpolate(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for Point3<f64> {
+    fn interpolate(&self, to: &Self, t: f32) -> Self { self.lerp(to, t as _) }
+}
+
+const SMOOTH_TICKS: f32 = 3.;
+
+pub struct Smoothed<T: Interpolate> {
+    from: T,
+    to: T,
+    start: Cell<Option<f32>>,
+    shown: Cell<T>,
+}
+
+impl<T: Interpolate> Smoothed<T> {
+    pub fn new(value: T) -> Self { Self { from: value, to: value, start: Some(f32::NEG_INFINITY).into(), shown: value.into() } }
+    pub fn set(&mut self, value: T) {
+        self.from = self.shown.get();
+        self.to = value;
+        self.start.set(None)
+    }
+
+    pub fn get(&self, time: f32) -> T {
+        let start = *self.start.get().get_or_insert(time);
+        self.start.set(Some(start));
+        let value = self.from.interpolate(&self.to, ((time - start) / SMOOTH_TICKS).clamp(0., 1.));
+        self.shown.set(value);
+        value
+    }
 }
 
 pub struct ClientDefs {
@@ -98,6 +179,11 @@ impl ClientDefs {
             .native_2(&mn.container_screen_mouse_clicked, container_screen_mouse_clicked_dyn())
             .native_2(&mn.container_screen_mouse_dragged, container_screen_mouse_dragged_dyn())
             .native_2(&mn.container_screen_mouse_released, container_screen_mouse_released_dyn())
+            .native_2(&mn.container_screen_mouse_scrolled, container_screen_mouse_scrolled_dyn())
+            .native_2(&mn.container_screen_has_clicked_outside, container_screen_has_clicked_outside_dyn())
+            .native_2(&mn.container_screen_key_pressed, container_screen_key_pressed_dyn())
+            .native_2(&mn.container_screen_char_typed, container_screen_char_typed_dyn())
+            .native_2(&mn.screen_update_narration, screen_update_narration_dyn())
             .define_empty();
         let pos_color_shader_supplier = ClassBuilder::new_1(av, namer, c"java/lang/Object")
             .interfaces([c"java/util/function/Supplier"])
@@ -133,7 +219,7 @@ fn container_screen_render_bg(jni: &JNI, this: usize, gui_graphics: usize, sub_t
     if menu.should_draw_dark_bg() {
         this.call_void_method(mvc.screen_render_background, &[gui_graphics, mx as _, my as _, f_raw(sub_tick)]).unwrap()
     }
-    menu.render_bg(&lk, this, BorrowedRef::new(jni, &gui_graphics), container_screen_rect(this, menu), point![mx, my])
+    menu.render_bg(&lk, this, BorrowedRef::new(jni, &gui_graphics), container_screen_rect(this, menu), point![mx, my], sub_tick)
 }
 
 #[dyn_abi]
@@ -166,10 +252,70 @@ fn container_screen_mouse_released(jni: &JNI, this: usize, mx: f64, my: f64, but
     let j_menu = this.get_object_field(mvc.container_screen_menu).unwrap();
     let lk = objs().mtx.lock(jni).unwrap();
     let menu = objs().gui_defs.menu.read(&lk, j_menu.borrow());
-    let false = menu.mouse_released(&lk, j_menu.borrow(), button) else { return true };
+    let false = menu.mouse_released(&lk, j_menu.borrow(), container_screen_rect(this, menu), point![mx, my].cast(), button) else { return true };
     this.call_nonvirtual_bool_method(mvc.container_screen.raw, mvc.container_screen_mouse_released, &[d_raw(mx), d_raw(my), button as _]).unwrap()
 }
 
+#[dyn_abi]
+fn container_screen_mouse_scrolled(jni: &JNI, this: usize, mx: f64, my: f64, dx: f64, dy: f64) -> bool {
+    let mvc = objs().mv.client.uref();
+    let this = BorrowedRef::new(jni, &this);
+    let j_menu = this.get_object_field(mvc.container_screen_menu).unwrap();
+    let lk = objs().mtx.lock(jni).unwrap();
+    let menu = objs().gui_defs.menu.read(&lk, j_menu.borrow());
+    let false = menu.mouse_scrolled(&lk, j_menu.borrow(), container_screen_rect(this, menu), point![mx, my].cast(), dy as _) else { return true };
+    this.call_nonvirtual_bool_method(mvc.container_screen.raw, mvc.container_screen_mouse_scrolled, &[d_raw(mx), d_raw(my), d_raw(dx), d_raw(dy)])
+        .unwrap()
+}
+
+#[dyn_abi]
+fn container_screen_has_clicked_outside(jni: &JNI, this: usize, mx: f64, my: f64, _left: i32, _top: i32, _button: i32) -> bool {
+    let mvc = objs().mv.client.uref();
+    let this = BorrowedRef::new(jni, &this);
+    let menu = this.get_object_field(mvc.container_screen_menu).unwrap();
+    let lk = objs().mtx.lock(jni).unwrap();
+    let menu = objs().gui_defs.menu.read(&lk, menu.borrow());
+    !menu.contains(container_screen_rect(this, menu), point![mx, my].cast())
+}
+
+#[dyn_abi]
+fn container_screen_key_pressed(jni: &JNI, this: usize, key: i32, scan_code: i32, modifiers: i32) -> bool {
+    let mvc = objs().mv.client.uref();
+    let this = BorrowedRef::new(jni, &this);
+    let j_menu = this.get_object_field(mvc.container_screen_menu).unwrap();
+    let lk = objs().mtx.lock(jni).unwrap();
+    let menu = objs().gui_defs.menu.read(&lk, j_menu.borrow());
+    if menu.key_pressed(&lk, j_menu.borrow(), key, modifiers) || menu.is_focused() {
+        return true;
+    }
+    this.call_nonvirtual_bool_method(mvc.container_screen.raw, mvc.container_screen_key_pressed, &[key as _, scan_code as _, modifiers as _]).unwrap()
+}
+
+#[dyn_abi]
+fn screen_update_narration(jni: &JNI, this: usize, output: usize) {
+    let mvc = objs().mv.client.uref();
+    let this = BorrowedRef::new(jni, &this);
+    let output = BorrowedRef::new(jni, &output);
+    this.call_nonvirtual_void_method(mvc.screen.raw, mvc.screen_update_narration, &[output.raw]).unwrap();
+    let menu = this.get_object_field(mvc.container_screen_menu).unwrap();
+    let lk = objs().mtx.lock(jni).unwrap();
+    objs().gui_defs.menu.read(&lk, menu.borrow()).update_narration(&lk, output)
+}
+
+#[dyn_abi]
+fn container_screen_char_typed(jni: &JNI, this: usize, ch: u16, modifiers: i32) -> bool {
+    let mvc = objs().mv.client.uref();
+    let this = BorrowedRef::new(jni, &this);
+    let j_menu = this.get_object_field(mvc.container_screen_menu).unwrap();
+    let lk = objs().mtx.lock(jni).unwrap();
+    let menu = objs().gui_defs.menu.read(&lk, j_menu.borrow());
+    if let Some(ch) = char::from_u32(ch as _) {
+        let false = menu.char_typed(&lk, j_menu.borrow(), ch) else { return true };
+    }
+    let false = menu.is_focused() else { return true };
+    this.call_nonvirtual_bool_method(mvc.container_screen.raw, mvc.container_screen_char_typed, &[ch as _, modifiers as _]).unwrap()
+}
+
 #[dyn_abi]
 fn container_screen_minit(jni: &JNI, this: usize) {
     let mvc = objs().mv.client.uref();
@@ -210,11 +356,12 @@ fn screen_constructor_create(jni: &JNI, _: usize, menu: usize, inv: usize, title
 }
 
 #[dyn_abi]
-fn render_tile(jni: &JNI, _: usize, tile: usize, _: f32, pose_stack: usize, buffer_source: usize, light: i32, overlay: i32) {
+fn render_tile(jni: &JNI, _: usize, tile: usize, sub_tick: f32, pose_stack: usize, buffer_source: usize, light: i32, overlay: i32) {
+    let time = client_ticks(jni) as f32 + sub_tick;
     let lk = objs().mtx.lock(jni).unwrap();
     let sr = SolidRenderer::new(&lk, &BorrowedRef::new(jni, &buffer_source), light, overlay);
     let pose = BorrowedRef::new(jni, &pose_stack).last_pose().read_pose();
-    objs().tile_defs.tile.read(&lk, BorrowedRef::new(jni, &tile)).render(&lk, sr, pose)
+    objs().tile_defs.tile.read(&lk, BorrowedRef::new(jni, &tile)).render(&lk, sr, pose, time)
 }
 
 #[derive(Clone, Copy)]
@@ -245,13 +392,22 @@ pub struct SolidRenderer<'a> {
     buffer: LocalRef<'a>,
     light: i32,
     overlay: i32,
+    color: u32,
 }
 
 impl<'a> SolidRenderer<'a> {
     pub fn new(lk: &GlobalMtx, buffer_source: &impl JRef<'a>, light: i32, overlay: i32) -> Self {
-        let sheets = lk.sheets_solid.get().unwrap().raw;
-        let buffer = buffer_source.call_object_method(objs().mv.client.uref().multi_buffer_source_get_buffer, &[sheets]).unwrap().unwrap();
-        Self { buffer, light, overlay }
+        Self::with_sheet(lk.sheets_solid.get().unwrap(), buffer_source, light, overlay, 0xFFFFFFFF)
+    }
+
+    // Caller must end the batch of `sheets_translucent`.
+    pub fn translucent(lk: &GlobalMtx, buffer_source: &impl JRef<'a>, light: i32, overlay: i32, alpha: u8) -> Self {
+        Self::with_sheet(lk.sheets_translucent.get().unwrap(), buffer_source, light, overlay, (alpha as u32) << 24 | 0xFFFFFF)
+    }
+
+    fn with_sheet(sheet: &GlobalRef, buffer_source: &impl JRef<'a>, light: i32, overlay: i32, color: u32) -> Self {
+        let buffer = buffer_source.call_object_method(objs().mv.client.uref().multi_buffer_source_get_buffer, &[sheet.raw]).unwrap().unwrap();
+        Self { buffer, light, overlay, color }
     }
 
     pub fn vertex(&mut self, p: Point3<f32>, n: Vector3<f32>, u: f32, v: f32) {
@@ -259,7 +415,7 @@ impl<'a> SolidRenderer<'a> {
             f_raw(p.x),
             f_raw(p.y),
             f_raw(p.z),
-            0xFFFFFFFF,
+            self.color as _,
             f_raw(u),
             f_raw(v),
             self.overlay as _,
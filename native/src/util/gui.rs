@@ -17,6 +17,35 @@ use core::{any::Any, cell::RefCell};
 use macros::dyn_abi;
 use nalgebra::{Point2, Vector2};
 
+// GLFW key codes
+pub const KEY_SPACE: i32 = 32;
+pub const KEY_B: i32 = 66;
+pub const KEY_ESCAPE: i32 = 256;
+pub const KEY_ENTER: i32 = 257;
+pub const KEY_TAB: i32 = 258;
+pub const KEY_BACKSPACE: i32 = 259;
+pub const KEY_RIGHT: i32 = 262;
+pub const KEY_LEFT: i32 = 263;
+pub const KEY_DOWN: i32 = 264;
+pub const KEY_UP: i32 = 265;
+pub const KEY_KP_ENTER: i32 = 335;
+pub const MOD_SHIFT: i32 = 1;
+pub const MOD_CONTROL: i32 = 2;
+
+// For mouse events, which aren't given modifiers. Same bits as key events.
+pub fn held_modifiers(jni: &JNI) -> i32 {
+    let mvc = objs().mv.client.uref();
+    let screen = mvc.screen.with_jni(jni);
+    let shift = screen.call_static_bool_method(mvc.screen_has_shift_down, &[]).unwrap();
+    let control = screen.call_static_bool_method(mvc.screen_has_control_down, &[]).unwrap();
+    shift as i32 * MOD_SHIFT | control as i32 * MOD_CONTROL
+}
+
+pub enum Narration {
+    Position,
+    Hint,
+}
+
 impl<'a, T: JRef<'a>> GUIExt<'a> for T {}
 pub trait GUIExt<'a>: JRef<'a> {
     fn player_container_menu(&self) -> Option<LocalRef<'a>> { self.get_object_field(objs().mv.player_container_menu) }
@@ -27,6 +56,16 @@ pub trait GUIExt<'a>: JRef<'a> {
         objs().mv.container.with_jni(self.jni()).call_static_bool_method(objs().mv.container_still_valid, &[self.raw(), player.raw()]).unwrap()
     }
 
+    // Called on NarrationElementOutput
+    fn narration_add(&self, kind: Narration, text: &impl JRef<'a>) {
+        let mvc = objs().mv.client.uref();
+        let kind = match kind {
+            Narration::Position => &mvc.narrated_element_type_position,
+            Narration::Hint => &mvc.narrated_element_type_hint,
+        };
+        self.call_void_method(mvc.narration_output_add, &[kind.raw, text.raw()]).unwrap()
+    }
+
     // Called on chat.Component
     fn to_formatted(&self) -> LocalRef<'a> { self.call_object_method(objs().mv.chat_component_to_formatted, &[]).unwrap().unwrap() }
 
@@ -40,18 +79,45 @@ pub trait GUIExt<'a>: JRef<'a> {
         let mv = &objs().mv;
         mv.chat_component.with_jni(self.jni()).call_static_object_method(mv.chat_component_translatable, &[self.raw()]).unwrap().unwrap()
     }
+
+    fn translatable_with(&self, args: &[LocalRef]) -> LocalRef<'a> {
+        let GlobalObjs { av, mv, .. } = objs();
+        let mut array = av.jv.object.with_jni(self.jni()).new_object_array(args.len() as _, 0).unwrap();
+        for (i, arg) in args.iter().enumerate() {
+            array.set_object_elem(i as _, arg.raw).unwrap()
+        }
+        mv.chat_component
+            .with_jni(self.jni())
+            .call_static_object_method(mv.chat_component_translatable_args, &[self.raw(), array.raw])
+            .unwrap()
+            .unwrap()
+    }
 }
 
 pub trait Menu: Cleanable {
     fn any(&self) -> &dyn Any;
     fn get_size(&self) -> Vector2<i32>;
     fn get_offset(&self) -> Vector2<i32>;
+    fn contains(&self, rect: Rect, pos: Point2<f32>) -> bool;
     fn should_draw_dark_bg(&self) -> bool;
-    fn render_bg(&self, lk: &GlobalMtx, screen: BorrowedRef, gui: BorrowedRef, rect: Rect, cursor: Point2<i32>);
+    fn render_bg(&self, lk: &GlobalMtx, screen: BorrowedRef, gui: BorrowedRef, rect: Rect, cursor: Point2<i32>, sub_tick: f32);
     fn mouse_clicked(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>, button: i32) -> bool;
     fn mouse_dragged(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>) -> bool;
-    fn mouse_released(&self, lk: &GlobalMtx, menu: BorrowedRef, button: i32) -> bool;
-    fn still_valid(&self, player: BorrowedRef) -> bool;
+    fn mouse_released(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>, button: i32) -> bool;
+    fn mouse_scrolled(&self, lk: &GlobalMtx, menu: BorrowedRef, rect: Rect, pos: Point2<f32>, delta: f32) -> bool;
+    fn key_pressed(&self, lk: &GlobalMtx, menu: BorrowedRef, key: i32, modifiers: i32) -> bool;
+    fn char_typed(&self, lk: &GlobalMtx, menu: BorrowedRef, ch: char) -> bool;
+    fn is_focused(&self) -> bool;
+    fn update_narration(&self, lk: &GlobalMtx, output: BorrowedRef);
+    fn still_valid(&self, lk: &GlobalMtx, player: BorrowedRef) -> bool;
+    // Called on both sides right after the menu is constructed. Both must add the same slots in the same order.
+    fn add_slots(&self, lk: &GlobalMtx, menu: BorrowedRef, inv: BorrowedRef);
+    // Slot range that shift-clicking slot `index` moves its stack into, and whether to fill it from the end.
+    fn quick_move_target(&self, index: i32) -> Option<(i32, i32, bool)>;
+    fn tick(&self, lk: &GlobalMtx, player: BorrowedRef<'static, '_>);
+    // Server side, polled every tick while the menu is open. Returns data for `receive_data` when the client's copy is stale.
+    fn sync_data(&self, lk: &GlobalMtx, jni: &JNI) -> Option<Vec<u8>>;
+    fn receive_data(&self, lk: &GlobalMtx, data: &[u8]) -> Result<()>;
 }
 
 pub trait MenuType: Send {
@@ -115,10 +181,10 @@ impl GUIDefs {
         player: &impl JRef<'static>,
         menu_type: &'static dyn MenuType,
         menu: Arc<dyn Menu>,
-        title: &impl JRef<'static>, // String
+        title: &impl JRef<'static>, // Component
         data: Vec<u8>,
     ) {
-        let title = title.translatable().new_global_ref().unwrap();
+        let title = title.new_global_ref().unwrap();
         let provider = MenuProvider { title, menu_type, menu: RefCell::new(Some(menu)), data };
         let provider = self.menu_provider.new_obj(player.jni(), Arc::new(provider));
         player.call_object_method(objs().fmv.player_open_menu, &[provider.raw, provider.raw]).unwrap();
@@ -138,6 +204,7 @@ fn container_factory_create(jni: &'static JNI, this: usize, id: i32, inv: usize,
         Ok(menu) => {
             let menu = gui_defs.menu.new_obj(jni, menu);
             menu.call_void_method(mv.container_menu_init, &[this.raw(&lk), id as _]).unwrap();
+            gui_defs.menu.read(&lk, menu.borrow()).add_slots(&lk, menu.borrow(), BorrowedRef::new(jni, &inv));
             menu.into_raw()
         }
         Err(e) => {
@@ -150,12 +217,13 @@ fn container_factory_create(jni: &'static JNI, this: usize, id: i32, inv: usize,
 }
 
 #[dyn_abi]
-fn menu_provider_create_menu(jni: &JNI, this: usize, id: i32, _inv: usize, _player: usize) -> usize {
+fn menu_provider_create_menu(jni: &JNI, this: usize, id: i32, inv: usize, _player: usize) -> usize {
     let GlobalObjs { mtx, gui_defs, mv, .. } = objs();
     let lk = mtx.lock(jni).unwrap();
     let this = gui_defs.menu_provider.read(&lk, BorrowedRef::new(jni, &this));
     let menu = gui_defs.menu.new_obj(jni, this.menu.borrow_mut().take().unwrap());
     menu.call_void_method(mv.container_menu_init, &[this.menu_type.raw(&lk), id as _]).unwrap();
+    gui_defs.menu.read(&lk, menu.borrow()).add_slots(&lk, menu.borrow(), BorrowedRef::new(jni, &inv));
     menu.into_raw()
 }
 
@@ -175,12 +243,40 @@ fn menu_provider_accept(jni: &JNI, this: usize, byte_buf: usize) {
     BorrowedRef::new(jni, &byte_buf).call_object_method(mv.friendly_byte_buf_write_byte_array, &[ba.raw]).unwrap();
 }
 
+pub fn tick_open_menu(lk: &GlobalMtx, player: BorrowedRef<'static, '_>) {
+    let GlobalObjs { gui_defs, net_defs, .. } = objs();
+    let Some(menu) = player.player_container_menu() else { return };
+    let true = menu.is_instance_of(gui_defs.menu.cls.cls.raw) else { return };
+    let this = gui_defs.menu.read(lk, menu.borrow());
+    this.tick(lk, player);
+    if let Some(data) = this.sync_data(lk, player.jni) {
+        net_defs.send_menu_data(&player, menu.menu_id(), data)
+    }
+}
+
 #[dyn_abi]
 fn still_valid(jni: &JNI, this: usize, player: usize) -> bool {
     let lk = objs().mtx.lock(jni).unwrap();
     let this = objs().gui_defs.menu.read(&lk, BorrowedRef::new(jni, &this));
-    this.still_valid(BorrowedRef::new(jni, &player))
+    this.still_valid(&lk, BorrowedRef::new(jni, &player))
 }
 
 #[dyn_abi]
-fn quick_move_stack(_jni: &JNI, _this: usize, _player: usize, _index: i32) -> usize { 0 }
+fn quick_move_stack(jni: &JNI, this: usize, player: usize, index: i32) -> usize {
+    let GlobalObjs { mtx, gui_defs, mv, .. } = objs();
+    let lk = mtx.lock(jni).unwrap();
+    let menu = BorrowedRef::new(jni, &this);
+    let Some((start, end, reverse)) = gui_defs.menu.read(&lk, menu).quick_move_target(index) else { return mv.item_stack_empty.raw };
+    let slot = menu.call_object_method(mv.container_menu_get_slot, &[index as _]).unwrap().unwrap();
+    let true = slot.call_bool_method(mv.slot_has_item, &[]).unwrap() else { return mv.item_stack_empty.raw };
+    let stack = slot.call_object_method(mv.slot_get_item, &[]).unwrap().unwrap();
+    let moved = stack.call_object_method(mv.item_stack_copy, &[]).unwrap().unwrap();
+    let count = stack.call_int_method(mv.item_stack_get_count, &[]).unwrap();
+    let args = [stack.raw, start as _, end as _, reverse as _];
+    let true = menu.call_bool_method(mv.container_menu_move_item_stack_to, &args).unwrap() else { return mv.item_stack_empty.raw };
+    // Item handler slots may hand out copies, so the remainder is written back instead of relying on the shrink.
+    slot.call_void_method(mv.slot_set_by_player, &[stack.raw]).unwrap();
+    let true = stack.call_int_method(mv.item_stack_get_count, &[]).unwrap() < count else { return mv.item_stack_empty.raw };
+    slot.call_void_method(mv.slot_on_take, &[player, stack.raw]).unwrap();
+    moved.into_raw()
+}
@@ -19,22 +19,41 @@ pub struct ForgeCN<T> {
     pub chunk_unwatch_evt: T,
     pub chunk_load_evt: T,
     pub chunk_unload_evt: T,
+    pub player_tick_evt: T,
     pub tile_ext: T,
     pub level_ext: T,
     pub reg_caps_evt: T,
     pub cap_provider: T,
+    pub item_handler: T,
+    pub slot_item_handler: T,
     pub parallel_dispatch_evt: T,
     pub reg_payload_evt: T,
     pub payload_reg: T,
     pub payload_handler: T,
     pub payload_ctx: T,
     pub pkt_distributor: T,
+    pub mod_container: T,
+    pub config_iface: T,
+    pub config_type: T,
+    pub config_spec: T,
+    pub config_builder: T,
+    pub config_value: T,
+    pub config_double_value: T,
+    pub config_long_value: T,
+    pub config_loading_evt: T,
+    pub config_reloading_evt: T,
     // Client
     pub render_lvl_stg_evt: T,
     pub render_lvl_stg: T,
     pub fml_client_setup_evt: T,
     pub renderers_evt: T,
     pub atlas_evt: T,
+    pub client_exts_evt: T,
+    pub client_item_exts: T,
+    pub key_mappings_evt: T,
+    pub client_tick_evt: T,
+    pub models_reg_evt: T,
+    pub models_baked_evt: T,
 }
 
 impl ForgeCN<Arc<CSig>> {
@@ -54,22 +73,41 @@ impl ForgeCN<Arc<CSig>> {
             chunk_unwatch_evt: b"net.neoforged.neoforge.event.level.ChunkWatchEvent$UnWatch",
             chunk_load_evt: b"net.neoforged.neoforge.event.level.ChunkEvent$Load",
             chunk_unload_evt: b"net.neoforged.neoforge.event.level.ChunkEvent$Unload",
+            player_tick_evt: b"net.neoforged.neoforge.event.tick.PlayerTickEvent$Post",
             tile_ext: b"net.neoforged.neoforge.common.extensions.IBlockEntityExtension",
             level_ext: b"net.neoforged.neoforge.common.extensions.ILevelExtension",
             reg_caps_evt: b"net.neoforged.neoforge.capabilities.RegisterCapabilitiesEvent",
             cap_provider: b"net.neoforged.neoforge.capabilities.ICapabilityProvider",
+            item_handler: b"net.neoforged.neoforge.items.IItemHandlerModifiable",
+            slot_item_handler: b"net.neoforged.neoforge.items.SlotItemHandler",
             parallel_dispatch_evt: b"net.neoforged.fml.event.lifecycle.ParallelDispatchEvent",
             reg_payload_evt: b"net.neoforged.neoforge.network.event.RegisterPayloadHandlersEvent",
             payload_reg: b"net.neoforged.neoforge.network.registration.PayloadRegistrar",
             payload_handler: b"net.neoforged.neoforge.network.handling.IPayloadHandler",
             payload_ctx: b"net.neoforged.neoforge.network.handling.IPayloadContext",
             pkt_distributor: b"net.neoforged.neoforge.network.PacketDistributor",
+            mod_container: b"net.neoforged.fml.ModContainer",
+            config_iface: b"net.neoforged.fml.config.IConfigSpec",
+            config_type: b"net.neoforged.fml.config.ModConfig$Type",
+            config_spec: b"net.neoforged.neoforge.common.ModConfigSpec",
+            config_builder: b"net.neoforged.neoforge.common.ModConfigSpec$Builder",
+            config_value: b"net.neoforged.neoforge.common.ModConfigSpec$ConfigValue",
+            config_double_value: b"net.neoforged.neoforge.common.ModConfigSpec$DoubleValue",
+            config_long_value: b"net.neoforged.neoforge.common.ModConfigSpec$LongValue",
+            config_loading_evt: b"net.neoforged.fml.event.config.ModConfigEvent$Loading",
+            config_reloading_evt: b"net.neoforged.fml.event.config.ModConfigEvent$Reloading",
             // Client
             render_lvl_stg_evt: b"net.neoforged.neoforge.client.event.RenderLevelStageEvent",
             render_lvl_stg: b"net.neoforged.neoforge.client.event.RenderLevelStageEvent$Stage",
             fml_client_setup_evt: b"net.neoforged.fml.event.lifecycle.FMLClientSetupEvent",
             renderers_evt: b"net.neoforged.neoforge.client.event.EntityRenderersEvent$RegisterRenderers",
             atlas_evt: b"net.neoforged.neoforge.client.event.TextureAtlasStitchedEvent",
+            client_exts_evt: b"net.neoforged.neoforge.client.extensions.common.RegisterClientExtensionsEvent",
+            client_item_exts: b"net.neoforged.neoforge.client.extensions.common.IClientItemExtensions",
+            key_mappings_evt: b"net.neoforged.neoforge.client.event.RegisterKeyMappingsEvent",
+            client_tick_evt: b"net.neoforged.neoforge.client.event.ClientTickEvent$Post",
+            models_reg_evt: b"net.neoforged.neoforge.client.event.ModelEvent$RegisterAdditional",
+            models_baked_evt: b"net.neoforged.neoforge.client.event.ModelEvent$ModifyBakingResult",
         };
         names.fmap(|x| Arc::new(CSig::new(x)))
     }
@@ -82,12 +120,22 @@ pub struct ForgeMN {
     pub enqueue_work: MSig,
     pub level_get_cap: MSig,
     pub cap_provider_get_cap: MSig,
+    pub item_handler_get_slots: MSig,
+    pub item_handler_get_stack: MSig,
+    pub item_handler_set_stack: MSig,
+    pub item_handler_insert: MSig,
+    pub item_handler_extract: MSig,
+    pub item_handler_get_slot_limit: MSig,
+    pub item_handler_is_valid: MSig,
     pub reg_caps_evt_reg_tile: MSig,
+    pub tile_on_load: MSig,
     pub reg_payload_evt_reg: MSig,
     pub payload_reg_bidir: MSig,
     pub handle_payload: MSig,
     pub send_c2s: MSig,
     pub send_s2c: MSig,
+    // Client
+    pub client_item_exts_get_renderer: MSig,
 }
 
 impl ForgeMN {
@@ -123,11 +171,39 @@ impl ForgeMN {
                 name: cs("getCapability"),
                 sig: cs("(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;"),
             },
+            item_handler_get_slots: MSig { owner: fcn.item_handler.clone(), name: cs("getSlots"), sig: cs("()I") },
+            item_handler_get_stack: MSig {
+                owner: fcn.item_handler.clone(),
+                name: cs("getStackInSlot"),
+                sig: msig([B("I")], cn.item_stack.sig.to_bytes()),
+            },
+            item_handler_set_stack: MSig {
+                owner: fcn.item_handler.clone(),
+                name: cs("setStackInSlot"),
+                sig: msig([b"I", cn.item_stack.sig.to_bytes()], b"V"),
+            },
+            item_handler_insert: MSig {
+                owner: fcn.item_handler.clone(),
+                name: cs("insertItem"),
+                sig: msig([b"I", cn.item_stack.sig.to_bytes(), b"Z"], cn.item_stack.sig.to_bytes()),
+            },
+            item_handler_extract: MSig {
+                owner: fcn.item_handler.clone(),
+                name: cs("extractItem"),
+                sig: msig([B("IIZ")], cn.item_stack.sig.to_bytes()),
+            },
+            item_handler_get_slot_limit: MSig { owner: fcn.item_handler.clone(), name: cs("getSlotLimit"), sig: cs("(I)I") },
+            item_handler_is_valid: MSig {
+                owner: fcn.item_handler.clone(),
+                name: cs("isItemValid"),
+                sig: msig([b"I", cn.item_stack.sig.to_bytes()], b"Z"),
+            },
             reg_caps_evt_reg_tile: MSig {
                 owner: fcn.reg_caps_evt.clone(),
                 name: cs("registerBlockEntity"),
                 sig: msig([fcn.block_cap.sig.to_bytes(), cn.tile_type.sig.to_bytes(), fcn.cap_provider.sig.to_bytes()], b"V"),
             },
+            tile_on_load: MSig { owner: fcn.tile_ext.clone(), name: cs("onLoad"), sig: cs("()V") },
             reg_payload_evt_reg: MSig {
                 owner: fcn.reg_payload_evt.clone(),
                 name: cs("registrar"),
@@ -156,6 +232,11 @@ impl ForgeMN {
                 name: cs("sendToPlayer"),
                 sig: msig([cn.server_player.sig.to_bytes(), cn.custom_payload.sig.to_bytes(), b"[", cn.custom_payload.sig.to_bytes()], b"V"),
             },
+            client_item_exts_get_renderer: MSig {
+                owner: fcn.client_item_exts.clone(),
+                name: cs("getCustomRenderer"),
+                sig: msig([], cn.item_renderer.sig.to_bytes()),
+            },
         }
     }
 }
@@ -175,21 +256,47 @@ pub struct ForgeMV {
     pub chunk_watch_level: usize,
     pub level_evt_level: usize,
     pub chunk_evt_chunk: usize,
+    pub player_evt_get_entity: usize,
     pub level_get_cap: usize,
     pub reg_caps_evt_reg_tile: usize,
+    pub slot_item_handler: GlobalRef<'static>,
+    pub slot_item_handler_init: usize,
     pub reg_payload_evt_reg: usize,
     pub payload_reg_bidir: usize,
     pub payload_ctx_flow: usize,
     pub payload_ctx_player: usize,
+    pub payload_ctx_disconnect: usize,
     pub pkt_flow_is_s2c: usize,
     pub pkt_distributor: GlobalRef<'static>,
     pub send_c2s: usize,
     pub send_s2c: usize,
+    pub mod_container: GlobalRef<'static>,
+    pub mod_container_reg_config: usize,
+    pub config_type_client: GlobalRef<'static>,
+    pub config_type_server: GlobalRef<'static>,
+    pub config_spec_is_loaded: usize,
+    pub config_builder: GlobalRef<'static>,
+    pub config_builder_init: usize,
+    pub config_builder_comment: usize,
+    pub config_builder_push: usize,
+    pub config_builder_pop: usize,
+    pub config_builder_define: usize,
+    pub config_builder_define_double: usize,
+    pub config_builder_define_long: usize,
+    pub config_builder_build: usize,
+    pub config_value_get: usize,
+    pub config_double_value_get: usize,
+    pub config_long_value_get: usize,
     pub client: Option<ForgeMVC>,
 }
 
 pub struct ForgeMVC {
     pub renderers_evt_reg: usize,
+    pub client_exts_evt_reg_item: usize,
+    pub key_mappings_evt_reg: usize,
+    pub models_reg_evt_reg: usize,
+    pub models_baked_evt_get_models: usize,
+    pub map_get: usize,
     pub atlas_evt_get_atlas: usize,
     pub render_lvl_stg_after_tiles: GlobalRef<'static>,
     pub render_lvl_stg_evt_stage: usize,
@@ -220,11 +327,15 @@ impl ForgeMV {
         let chunk_watch_base = load(&fcn.chunk_watch_base);
         let chunk_evt = av.ldr.load_class(&av.jv, c"net.neoforged.neoforge.event.level.ChunkEvent").unwrap();
         let level_evt = av.ldr.load_class(&av.jv, c"net.neoforged.neoforge.event.level.LevelEvent").unwrap();
+        let player_evt = av.ldr.load_class(&av.jv, c"net.neoforged.neoforge.event.entity.player.PlayerEvent").unwrap();
         let payload_ctx = load(&fcn.payload_ctx);
         let pkt_flow_ext = av.ldr.load_class(&av.jv, c"net.neoforged.neoforge.common.extensions.IPacketFlowExtension").unwrap();
         let pkt_distributor = load(&fcn.pkt_distributor);
+        let slot_item_handler = load(&fcn.slot_item_handler);
         let dist = fml.static_field_1(c"dist", c"Lnet/neoforged/api/distmarker/Dist;");
         let is_client = dist.call_bool_method(dist.get_object_class().get_method_id(c"isClient", c"()Z").unwrap(), &[]).unwrap();
+        let config_builder = load(&fcn.config_builder);
+        let config_builder_sig = fcn.config_builder.sig.to_bytes();
         Self {
             mod_evt_bus,
             com_evt_bus: forge.static_field_1(c"EVENT_BUS", &fcn.evt_bus.sig),
@@ -240,22 +351,64 @@ impl ForgeMV {
             chunk_watch_pos: chunk_watch_base.get_field_id(c"pos", &cn.chunk_pos.sig).unwrap(),
             chunk_evt_chunk: chunk_evt.get_field_id(c"chunk", &cn.chunk_access.sig).unwrap(),
             level_evt_level: level_evt.get_field_id(c"level", &cn.level_access.sig).unwrap(),
+            player_evt_get_entity: player_evt.get_method_id(c"getEntity", &msig([], cn.player.sig.to_bytes())).unwrap(),
             level_get_cap: fmn.level_get_cap.get_method_id(&load(&fcn.level_ext)).unwrap(),
             reg_caps_evt_reg_tile: fmn.reg_caps_evt_reg_tile.get_method_id(&load(&fcn.reg_caps_evt)).unwrap(),
+            slot_item_handler_init: slot_item_handler.get_method_id(c"<init>", &msig([fcn.item_handler.sig.to_bytes(), b"III"], b"V")).unwrap(),
+            slot_item_handler,
             reg_payload_evt_reg: fmn.reg_payload_evt_reg.get_method_id(&load(&fcn.reg_payload_evt)).unwrap(),
             payload_reg_bidir: fmn.payload_reg_bidir.get_method_id(&load(&fcn.payload_reg)).unwrap(),
             payload_ctx_flow: payload_ctx.get_method_id(c"flow", &msig([], cn.pkt_flow.sig.to_bytes())).unwrap(),
             payload_ctx_player: payload_ctx.get_method_id(c"player", &msig([], &cn.player.sig.to_bytes())).unwrap(),
+            payload_ctx_disconnect: payload_ctx.get_method_id(c"disconnect", &msig([cn.chat_component.sig.to_bytes()], b"V")).unwrap(),
             pkt_flow_is_s2c: pkt_flow_ext.get_method_id(c"isClientbound", c"()Z").unwrap(),
             send_c2s: fmn.send_c2s.get_static_method_id(&pkt_distributor).unwrap(),
             send_s2c: fmn.send_s2c.get_static_method_id(&pkt_distributor).unwrap(),
             pkt_distributor,
+            mod_container_reg_config: load(&fcn.mod_container)
+                .get_method_id(c"registerConfig", &msig([fcn.config_type.sig.to_bytes(), fcn.config_iface.sig.to_bytes()], b"V"))
+                .unwrap(),
+            mod_container: container_inst.new_global_ref().unwrap(),
+            config_type_client: load(&fcn.config_type).static_field_1(c"CLIENT", &fcn.config_type.sig),
+            config_type_server: load(&fcn.config_type).static_field_1(c"SERVER", &fcn.config_type.sig),
+            config_spec_is_loaded: load(&fcn.config_spec).get_method_id(c"isLoaded", c"()Z").unwrap(),
+            config_builder_init: config_builder.get_method_id(c"<init>", c"()V").unwrap(),
+            config_builder_comment: config_builder.get_method_id(c"comment", &msig([B("Ljava/lang/String;")], config_builder_sig)).unwrap(),
+            config_builder_push: config_builder.get_method_id(c"push", &msig([B("Ljava/lang/String;")], config_builder_sig)).unwrap(),
+            config_builder_pop: config_builder.get_method_id(c"pop", &msig([], config_builder_sig)).unwrap(),
+            config_builder_define: config_builder
+                .get_method_id(c"define", &msig([B("Ljava/lang/String;Ljava/lang/Object;")], fcn.config_value.sig.to_bytes()))
+                .unwrap(),
+            config_builder_define_double: config_builder
+                .get_method_id(c"defineInRange", &msig([B("Ljava/lang/String;DDD")], fcn.config_double_value.sig.to_bytes()))
+                .unwrap(),
+            config_builder_define_long: config_builder
+                .get_method_id(c"defineInRange", &msig([B("Ljava/lang/String;JJJ")], fcn.config_long_value.sig.to_bytes()))
+                .unwrap(),
+            config_builder_build: config_builder.get_method_id(c"build", &msig([], fcn.config_spec.sig.to_bytes())).unwrap(),
+            config_builder,
+            config_value_get: load(&fcn.config_value).get_method_id(c"get", c"()Ljava/lang/Object;").unwrap(),
+            config_double_value_get: load(&fcn.config_double_value).get_method_id(c"getAsDouble", c"()D").unwrap(),
+            config_long_value_get: load(&fcn.config_long_value).get_method_id(c"getAsLong", c"()J").unwrap(),
             client: is_client.then(|| {
                 let renderers_evt = load(&fcn.renderers_evt);
                 let renderers_evt_reg = msig([cn.tile_type.sig.to_bytes(), cn.tile_renderer_provider.sig.to_bytes()], b"V");
                 let atlas_evt = load(&fcn.atlas_evt);
+                let client_exts_evt_reg_item = msig([fcn.client_item_exts.sig.to_bytes(), b"[", cn.item.sig.to_bytes()], b"V");
                 ForgeMVC {
                     renderers_evt_reg: renderers_evt.get_method_id(c"registerBlockEntityRenderer", &renderers_evt_reg).unwrap(),
+                    client_exts_evt_reg_item: load(&fcn.client_exts_evt).get_method_id(c"registerItem", &client_exts_evt_reg_item).unwrap(),
+                    key_mappings_evt_reg: load(&fcn.key_mappings_evt)
+                        .get_method_id(c"register", &msig([cn.key_mapping.sig.to_bytes()], b"V"))
+                        .unwrap(),
+                    models_reg_evt_reg: load(&fcn.models_reg_evt).get_method_id(c"register", &msig([cn.model_res_loc.sig.to_bytes()], b"V")).unwrap(),
+                    models_baked_evt_get_models: load(&fcn.models_baked_evt).get_method_id(c"getModels", c"()Ljava/util/Map;").unwrap(),
+                    map_get: av
+                        .ldr
+                        .load_class(&av.jv, c"java.util.Map")
+                        .unwrap()
+                        .get_method_id(c"get", c"(Ljava/lang/Object;)Ljava/lang/Object;")
+                        .unwrap(),
                     atlas_evt_get_atlas: atlas_evt.get_method_id(c"getAtlas", &msig([], cn.atlas.sig.to_bytes())).unwrap(),
                     render_lvl_stg_after_tiles: load(&fcn.render_lvl_stg).static_field_1(c"AFTER_BLOCK_ENTITIES", &fcn.render_lvl_stg.sig),
                     render_lvl_stg_evt_stage: load(&fcn.render_lvl_stg_evt).get_field_id(c"stage", &fcn.render_lvl_stg.sig).unwrap(),
@@ -283,6 +436,11 @@ pub struct CN<T> {
     pub resource_key: T,
     pub item: T,
     pub item_props: T,
+    pub item_tooltip_ctx: T,
+    pub tooltip_flag: T,
+    pub data_components: T,
+    pub data_component_type: T,
+    pub custom_data: T,
     pub item_stack: T,
     pub item_like: T,
     pub block_item: T,
@@ -293,6 +451,20 @@ pub struct CN<T> {
     pub vec3d: T,
     pub block_pos: T,
     pub block_state: T,
+    pub block_state_props: T,
+    pub dir_prop: T,
+    pub bool_prop: T,
+    pub int_prop: T,
+    pub prop: T,
+    pub state_def_builder: T,
+    pub state_holder: T,
+    pub rotation: T,
+    pub mirror: T,
+    pub simple_waterlogged: T,
+    pub fluid: T,
+    pub flowing_fluid: T,
+    pub fluids: T,
+    pub fluid_state: T,
     pub dfu_type: T,
     pub sound_type: T,
     pub creative_tab_items_gen: T,
@@ -306,12 +478,19 @@ pub struct CN<T> {
     pub block_place_ctx: T,
     pub use_on_ctx: T,
     pub interaction_result: T,
+    pub item_interaction_result: T,
+    pub interaction_hand: T,
     pub s2c_tile_data: T,
     pub nbt_compound: T,
+    pub nbt_list: T,
+    pub nbt_string: T,
+    pub nbt_tag: T,
     pub pkt: T,
     pub living_entity: T,
     pub dir: T,
     pub loot_builder: T,
+    pub loot_ctx_param: T,
+    pub loot_ctx_params: T,
     pub container_menu: T,
     pub inventory: T,
     pub friendly_byte_buf: T,
@@ -325,13 +504,20 @@ pub struct CN<T> {
     pub chat_fmt: T,
     pub formatted_char_seq: T,
     pub block_hit_result: T,
+    pub hit_result: T,
     pub entity: T,
     pub container: T,
+    pub slot: T,
     pub game_profile: T,
+    pub player_team: T,
+    pub mc_server: T,
+    pub profile_cache: T,
     pub holder: T,
     pub holder_ref: T,
     pub holder_provider: T,
     pub sound_evts: T,
+    pub sound_source: T,
+    pub random_source: T,
     pub chunk_source: T,
     pub server_chunk_cache: T,
     pub tile_ticker: T,
@@ -339,6 +525,7 @@ pub struct CN<T> {
     pub chunk_pos: T,
     pub chunk_access: T,
     pub level_access: T,
+    pub level_reader: T,
     pub level_chunk: T,
     pub registry: T,
     pub registries: T,
@@ -350,6 +537,10 @@ pub struct CN<T> {
     pub tile_renderer: T,
     pub tile_renderer_provider: T,
     pub tile_renderer_provider_ctx: T,
+    pub item_renderer: T,
+    pub item_display_ctx: T,
+    pub key_mapping: T,
+    pub model_res_loc: T,
     pub pose_stack: T,
     pub pose: T,
     pub matrix4f: T,
@@ -366,6 +557,8 @@ pub struct CN<T> {
     pub container_screen: T,
     pub screen: T,
     pub gui_graphics: T,
+    pub narration_element_output: T,
+    pub narrated_element_type: T,
     pub font: T,
     pub game_renderer: T,
     pub render_sys: T,
@@ -380,6 +573,7 @@ pub struct CN<T> {
     pub sound_mgr: T,
     pub sound_inst: T,
     pub simple_sound_inst: T,
+    pub sound_attenuation: T,
     pub level_renderer: T,
     pub render_buffers: T,
     pub camera: T,
@@ -403,6 +597,11 @@ impl CN<Arc<CSig>> {
             resource_key: b"net.minecraft.resources.ResourceKey",
             item: b"net.minecraft.world.item.Item",
             item_props: b"net.minecraft.world.item.Item$Properties",
+            item_tooltip_ctx: b"net.minecraft.world.item.Item$TooltipContext",
+            tooltip_flag: b"net.minecraft.world.item.TooltipFlag",
+            data_components: b"net.minecraft.core.component.DataComponents",
+            data_component_type: b"net.minecraft.core.component.DataComponentType",
+            custom_data: b"net.minecraft.world.item.component.CustomData",
             item_stack: b"net.minecraft.world.item.ItemStack",
             item_like: b"net.minecraft.world.level.ItemLike",
             block_item: b"net.minecraft.world.item.BlockItem",
@@ -413,6 +612,20 @@ impl CN<Arc<CSig>> {
             vec3d: b"net.minecraft.world.phys.Vec3",
             block_pos: b"net.minecraft.core.BlockPos",
             block_state: b"net.minecraft.world.level.block.state.BlockState",
+            block_state_props: b"net.minecraft.world.level.block.state.properties.BlockStateProperties",
+            dir_prop: b"net.minecraft.world.level.block.state.properties.DirectionProperty",
+            bool_prop: b"net.minecraft.world.level.block.state.properties.BooleanProperty",
+            int_prop: b"net.minecraft.world.level.block.state.properties.IntegerProperty",
+            prop: b"net.minecraft.world.level.block.state.properties.Property",
+            state_def_builder: b"net.minecraft.world.level.block.state.StateDefinition$Builder",
+            state_holder: b"net.minecraft.world.level.block.state.StateHolder",
+            rotation: b"net.minecraft.world.level.block.Rotation",
+            mirror: b"net.minecraft.world.level.block.Mirror",
+            simple_waterlogged: b"net.minecraft.world.level.block.SimpleWaterloggedBlock",
+            fluid: b"net.minecraft.world.level.material.Fluid",
+            flowing_fluid: b"net.minecraft.world.level.material.FlowingFluid",
+            fluids: b"net.minecraft.world.level.material.Fluids",
+            fluid_state: b"net.minecraft.world.level.material.FluidState",
             dfu_type: b"com.mojang.datafixers.types.Type",
             sound_type: b"net.minecraft.world.level.block.SoundType",
             voxel_shape: b"net.minecraft.world.phys.shapes.VoxelShape",
@@ -426,12 +639,19 @@ impl CN<Arc<CSig>> {
             block_place_ctx: b"net.minecraft.world.item.context.BlockPlaceContext",
             use_on_ctx: b"net.minecraft.world.item.context.UseOnContext",
             interaction_result: b"net.minecraft.world.InteractionResult",
+            item_interaction_result: b"net.minecraft.world.ItemInteractionResult",
+            interaction_hand: b"net.minecraft.world.InteractionHand",
             s2c_tile_data: b"net.minecraft.network.protocol.game.ClientboundBlockEntityDataPacket",
             nbt_compound: b"net.minecraft.nbt.CompoundTag",
+            nbt_list: b"net.minecraft.nbt.ListTag",
+            nbt_string: b"net.minecraft.nbt.StringTag",
+            nbt_tag: b"net.minecraft.nbt.Tag",
             pkt: b"net.minecraft.network.protocol.Packet",
             living_entity: b"net.minecraft.world.entity.LivingEntity",
             dir: b"net.minecraft.core.Direction",
             loot_builder: b"net.minecraft.world.level.storage.loot.LootParams$Builder",
+            loot_ctx_param: b"net.minecraft.world.level.storage.loot.parameters.LootContextParam",
+            loot_ctx_params: b"net.minecraft.world.level.storage.loot.parameters.LootContextParams",
             container_menu: b"net.minecraft.world.inventory.AbstractContainerMenu",
             inventory: b"net.minecraft.world.entity.player.Inventory",
             friendly_byte_buf: b"net.minecraft.network.FriendlyByteBuf",
@@ -445,13 +665,20 @@ impl CN<Arc<CSig>> {
             chat_fmt: b"net.minecraft.ChatFormatting",
             formatted_char_seq: b"net.minecraft.util.FormattedCharSequence",
             block_hit_result: b"net.minecraft.world.phys.BlockHitResult",
+            hit_result: b"net.minecraft.world.phys.HitResult",
             entity: b"net.minecraft.world.entity.Entity",
             container: b"net.minecraft.world.Container",
+            slot: b"net.minecraft.world.inventory.Slot",
             game_profile: b"com.mojang.authlib.GameProfile",
+            player_team: b"net.minecraft.world.scores.PlayerTeam",
+            mc_server: b"net.minecraft.server.MinecraftServer",
+            profile_cache: b"net.minecraft.server.players.GameProfileCache",
             holder: b"net.minecraft.core.Holder",
             holder_ref: b"net.minecraft.core.Holder$Reference",
             holder_provider: b"net.minecraft.core.HolderLookup$Provider",
             sound_evts: b"net.minecraft.sounds.SoundEvents",
+            sound_source: b"net.minecraft.sounds.SoundSource",
+            random_source: b"net.minecraft.util.RandomSource",
             chunk_source: b"net.minecraft.world.level.chunk.ChunkSource",
             server_chunk_cache: b"net.minecraft.server.level.ServerChunkCache",
             tile_ticker: b"net.minecraft.world.level.block.entity.BlockEntityTicker",
@@ -459,6 +686,7 @@ impl CN<Arc<CSig>> {
             chunk_pos: b"net.minecraft.world.level.ChunkPos",
             chunk_access: b"net.minecraft.world.level.chunk.ChunkAccess",
             level_access: b"net.minecraft.world.level.LevelAccessor",
+            level_reader: b"net.minecraft.world.level.LevelReader",
             level_chunk: b"net.minecraft.world.level.chunk.LevelChunk",
             registry: b"net.minecraft.core.Registry",
             registries: b"net.minecraft.core.registries.Registries",
@@ -470,6 +698,10 @@ impl CN<Arc<CSig>> {
             tile_renderer: b"net.minecraft.client.renderer.blockentity.BlockEntityRenderer",
             tile_renderer_provider: b"net.minecraft.client.renderer.blockentity.BlockEntityRendererProvider",
             tile_renderer_provider_ctx: b"net.minecraft.client.renderer.blockentity.BlockEntityRendererProvider$Context",
+            item_renderer: b"net.minecraft.client.renderer.BlockEntityWithoutLevelRenderer",
+            item_display_ctx: b"net.minecraft.world.item.ItemDisplayContext",
+            key_mapping: b"net.minecraft.client.KeyMapping",
+            model_res_loc: b"net.minecraft.client.resources.model.ModelResourceLocation",
             pose_stack: b"com.mojang.blaze3d.vertex.PoseStack",
             pose: b"com.mojang.blaze3d.vertex.PoseStack$Pose",
             matrix4f: b"org.joml.Matrix4f",
@@ -486,6 +718,8 @@ impl CN<Arc<CSig>> {
             container_screen: b"net.minecraft.client.gui.screens.inventory.AbstractContainerScreen",
             screen: b"net.minecraft.client.gui.screens.Screen",
             gui_graphics: b"net.minecraft.client.gui.GuiGraphics",
+            narration_element_output: b"net.minecraft.client.gui.narration.NarrationElementOutput",
+            narrated_element_type: b"net.minecraft.client.gui.narration.NarratedElementType",
             font: b"net.minecraft.client.gui.Font",
             game_renderer: b"net.minecraft.client.renderer.GameRenderer",
             render_sys: b"com.mojang.blaze3d.systems.RenderSystem",
@@ -500,6 +734,7 @@ impl CN<Arc<CSig>> {
             sound_mgr: b"net.minecraft.client.sounds.SoundManager",
             sound_inst: b"net.minecraft.client.resources.sounds.SoundInstance",
             simple_sound_inst: b"net.minecraft.client.resources.sounds.SimpleSoundInstance",
+            sound_attenuation: b"net.minecraft.client.resources.sounds.SoundInstance$Attenuation",
             level_renderer: b"net.minecraft.client.renderer.LevelRenderer",
             render_buffers: b"net.minecraft.client.renderer.RenderBuffers",
             camera: b"net.minecraft.client.Camera",
@@ -518,19 +753,28 @@ pub struct MN<T> {
     pub tile_block_new_tile: T,
     pub tile_block_get_ticker: T,
     pub block_default_state: T,
+    pub block_create_state_def: T,
+    pub block_get_state_for_placement: T,
     pub block_beh_props_of: T,
     pub block_beh_props_strength: T,
     pub block_beh_props_dyn_shape: T,
     pub block_beh_props_sound: T,
+    pub block_beh_props_light_level: T,
     pub block_beh_get_render_shape: T,
     pub block_beh_get_shape: T,
     pub block_beh_get_drops: T,
     pub block_beh_on_place: T,
     pub block_beh_use: T,
+    pub block_beh_use_item_on: T,
+    pub block_beh_rotate: T,
+    pub block_beh_mirror: T,
+    pub block_beh_get_fluid_state: T,
+    pub block_beh_update_shape: T,
     pub block_item_init: T,
     pub block_item_place_block: T,
     pub block_getter_get_block_state: T,
     pub block_getter_get_tile: T,
+    pub block_getter_get_fluid_state: T,
     pub vec3i_x: T,
     pub vec3i_y: T,
     pub vec3i_z: T,
@@ -541,6 +785,20 @@ pub struct MN<T> {
     pub block_pos_init: T,
     pub block_state_get_block: T,
     pub block_state_get_visual_shape: T,
+    pub block_state_can_be_replaced: T,
+    pub block_state_props_facing: T,
+    pub block_state_props_waterlogged: T,
+    pub block_state_props_level: T,
+    pub state_def_builder_add: T,
+    pub state_holder_get_value: T,
+    pub state_holder_set_value: T,
+    pub rotation_rotate: T,
+    pub mirror_get_rotation: T,
+    pub fluids_water: T,
+    pub flowing_fluid_get_source: T,
+    pub fluid_get_tick_delay: T,
+    pub fluid_state_get_type: T,
+    pub level_access_schedule_fluid_tick: T,
     pub blocks_fire: T,
     pub tile_supplier_create: T,
     pub tile_type_init: T,
@@ -553,9 +811,27 @@ pub struct MN<T> {
     pub tile_pos: T,
     pub tile_set_removed: T,
     pub tile_set_changed: T,
+    pub tile_set_block_state: T,
     pub sound_type_metal: T,
     pub item_get_desc_id: T,
+    pub block_get_desc_id: T,
     pub item_stack_init: T,
+    pub item_stack_get_item: T,
+    pub item_stack_empty: T,
+    pub item_stack_is_empty: T,
+    pub item_stack_get_count: T,
+    pub item_stack_copy: T,
+    pub item_stack_copy_with_count: T,
+    pub item_stack_get_component: T,
+    pub item_stack_set_component: T,
+    pub item_append_hover_text: T,
+    pub data_components_custom_data: T,
+    pub data_components_custom_name: T,
+    pub custom_data_of: T,
+    pub custom_data_copy_tag: T,
+    pub item_init: T,
+    pub item_props_init: T,
+    pub item_props_stacks_to: T,
     pub creative_tab_items_gen_accept: T,
     pub render_shape_tile: T,
     pub resource_loc_init: T,
@@ -565,36 +841,86 @@ pub struct MN<T> {
     pub nbt_compound_init: T,
     pub nbt_compound_put_byte_array: T,
     pub nbt_compound_get_byte_array: T,
+    pub nbt_compound_contains: T,
+    pub nbt_compound_put: T,
+    pub nbt_compound_put_byte: T,
+    pub nbt_compound_put_int: T,
+    pub nbt_compound_get_int: T,
+    pub nbt_compound_put_bool: T,
+    pub nbt_compound_get_bool: T,
+    pub nbt_compound_put_float: T,
+    pub nbt_compound_get_float: T,
+    pub nbt_compound_put_long: T,
+    pub nbt_compound_get_long: T,
+    pub nbt_compound_get_list: T,
+    pub nbt_compound_get_compound: T,
+    pub nbt_compound_put_string: T,
+    pub nbt_compound_get_string: T,
+    pub nbt_compound_put_uuid: T,
+    pub nbt_compound_has_uuid: T,
+    pub nbt_compound_get_uuid: T,
+    pub nbt_list_init: T,
+    pub nbt_list_add: T,
+    pub nbt_list_size: T,
+    pub nbt_list_get_string: T,
+    pub nbt_string_value_of: T,
     pub use_on_ctx_get_level: T,
     pub use_on_ctx_get_clicked_pos: T,
     pub use_on_ctx_get_clicked_face: T,
+    pub use_on_ctx_get_item: T,
+    pub use_on_ctx_get_player: T,
     pub dir_3d_data: T,
     pub dir_by_3d_data: T,
     pub level_set_block_and_update: T,
+    pub level_set_block: T,
+    pub level_remove_block: T,
     pub level_update_neighbors_for_out_signal: T,
     pub level_is_client: T,
     pub level_get_chunk_source: T,
     pub level_is_outside_build_height: T,
+    pub level_get_game_time: T,
     pub container_menu_init: T,
     pub container_menu_still_valid: T,
     pub container_menu_quick_move_stack: T,
     pub container_menu_id: T,
+    pub container_menu_add_slot: T,
+    pub container_menu_get_slot: T,
+    pub container_menu_move_item_stack_to: T,
+    pub slot_init: T,
+    pub slot_has_item: T,
+    pub slot_get_item: T,
+    pub slot_set_by_player: T,
+    pub slot_on_take: T,
     pub menu_provider_create_menu: T,
     pub menu_provider_get_display_name: T,
     pub chat_component_translatable: T,
+    pub chat_component_translatable_args: T,
     pub chat_component_literal: T,
+    pub chat_component_get_string: T,
     pub chat_component_to_formatted: T,
+    pub chat_mutable_component_with_style: T,
     pub chat_fmt_from_code: T,
     pub chat_fmt_color: T,
     pub friendly_byte_buf_read_byte_array: T,
     pub friendly_byte_buf_write_byte_array: T,
     pub inventory_player: T,
+    pub inventory_place_back: T,
+    pub player_get_inventory: T,
+    pub entity_is_shift_key_down: T,
     pub entity_level: T,
     pub entity_y_rot: T,
     pub container_still_valid: T,
     pub player_profile: T,
     pub player_container_menu: T,
     pub game_profile_get_name: T,
+    pub entity_get_uuid: T,
+    pub entity_get_team: T,
+    pub entity_get_server: T,
+    pub mc_server_get_profile_cache: T,
+    pub profile_cache_get: T,
+    pub player_team_get_players: T,
+    pub player_has_permissions: T,
+    pub player_display_client_message: T,
     pub sound_evts_ui_btn_click: T,
     pub server_chunk_cache_block_changed: T,
     pub tile_ticker_tick: T,
@@ -604,6 +930,9 @@ pub struct MN<T> {
     pub block_hit_result_pos: T,
     pub block_hit_result_miss: T,
     pub block_hit_result_dir: T,
+    pub block_hit_result_block_pos: T,
+    pub living_entity_main_hand_item: T,
+    pub living_entity_offhand_item: T,
     pub chunk_access_pos: T,
     pub level_chunk_set_block_state: T,
     pub level_chunk_level: T,
@@ -612,6 +941,9 @@ pub struct MN<T> {
     pub reg_key_block: T,
     pub reg_key_tile_type: T,
     pub reg_key_menu: T,
+    pub reg_key_item: T,
+    pub loot_builder_get_optional: T,
+    pub loot_ctx_params_tile: T,
     pub custom_payload_type: T,
     pub custom_payload_type_init: T,
     pub stream_codec_encode: T,
@@ -619,9 +951,12 @@ pub struct MN<T> {
     // Client
     pub tile_renderer_render: T,
     pub tile_renderer_provider_create: T,
+    pub item_renderer_render: T,
     pub pose_pose: T,
     pub pose_stack_last: T,
     pub matrix4fc_read: T,
+    pub matrix4f_init: T,
+    pub matrix4f_write: T,
     pub atlas_loc: T,
     pub atlas_loc_blocks: T,
     pub atlas_get_sprite: T,
@@ -630,6 +965,7 @@ pub struct MN<T> {
     pub sprite_u1: T,
     pub sprite_v1: T,
     pub sheets_solid: T,
+    pub sheets_translucent: T,
     pub multi_buffer_source_get_buffer: T,
     pub buffer_source_end_batch: T,
     pub vertex_consumer_vertex: T,
@@ -642,6 +978,12 @@ pub struct MN<T> {
     pub screen_width: T,
     pub screen_height: T,
     pub screen_render_background: T,
+    pub screen_has_shift_down: T,
+    pub screen_has_control_down: T,
+    pub screen_update_narration: T,
+    pub narration_output_add: T,
+    pub narrated_element_type_position: T,
+    pub narrated_element_type_hint: T,
     pub container_screen_init: T,
     pub container_screen_minit: T,
     pub container_screen_img_width: T,
@@ -656,8 +998,13 @@ pub struct MN<T> {
     pub container_screen_mouse_clicked: T,
     pub container_screen_mouse_dragged: T,
     pub container_screen_mouse_released: T,
+    pub container_screen_mouse_scrolled: T,
+    pub container_screen_has_clicked_outside: T,
+    pub container_screen_key_pressed: T,
+    pub container_screen_char_typed: T,
     pub gui_graphics_draw_formatted: T,
     pub gui_graphics_pose: T,
+    pub gui_graphics_buffer_source: T,
     pub render_sys_set_shader: T,
     pub render_sys_enable_blend: T,
     pub render_sys_enable_cull: T,
@@ -674,12 +1021,26 @@ pub struct MN<T> {
     pub mc_get_sound_mgr: T,
     pub mc_clear_level: T,
     pub mc_player: T,
+    pub mc_level_renderer: T,
+    pub mc_hit_result: T,
+    pub key_mapping_init: T,
+    pub key_mapping_consume_click: T,
+    pub model_res_loc_standalone: T,
+    pub model_res_loc_inventory: T,
     pub window_get_gui_scale: T,
     pub font_width: T,
     pub sound_mgr_play: T,
+    pub sound_mgr_stop: T,
     pub simple_sound_inst_for_ui_holder: T,
+    pub simple_sound_inst_init: T,
+    pub sound_inst_create_random: T,
+    pub sound_source_blocks: T,
+    pub sound_attenuation_linear: T,
     pub render_type_lightning: T,
+    pub render_type_lines: T,
+    pub level_renderer_render_line_box: T,
     pub level_renderer_buffers: T,
+    pub level_renderer_ticks: T,
     pub render_buffers_buffer_source: T,
     pub camera_pos: T,
     pub buffer_uploader_draw: T,
@@ -705,6 +1066,16 @@ impl MN<MSig> {
                 sig: msig([cn.level.sig.to_bytes(), cn.block_state.sig.to_bytes(), cn.tile_type.sig.to_bytes()], cn.tile_ticker.sig.to_bytes()),
             },
             block_default_state: MSig { owner: cn.block.clone(), name: cs("defaultBlockState"), sig: msig([], cn.block_state.sig.to_bytes()) },
+            block_create_state_def: MSig {
+                owner: cn.block.clone(),
+                name: cs("createBlockStateDefinition"),
+                sig: msig([cn.state_def_builder.sig.to_bytes()], b"V"),
+            },
+            block_get_state_for_placement: MSig {
+                owner: cn.block.clone(),
+                name: cs("getStateForPlacement"),
+                sig: msig([cn.block_place_ctx.sig.to_bytes()], cn.block_state.sig.to_bytes()),
+            },
             block_beh_props_of: MSig { owner: cn.block_beh_props.clone(), name: cs("of"), sig: msig([], cn.block_beh_props.sig.to_bytes()) },
             block_beh_props_strength: MSig {
                 owner: cn.block_beh_props.clone(),
@@ -721,6 +1092,11 @@ impl MN<MSig> {
                 name: cs("sound"),
                 sig: msig([cn.sound_type.sig.to_bytes()], cn.block_beh_props.sig.to_bytes()),
             },
+            block_beh_props_light_level: MSig {
+                owner: cn.block_beh_props.clone(),
+                name: cs("lightLevel"),
+                sig: msig([B("Ljava/util/function/ToIntFunction;")], cn.block_beh_props.sig.to_bytes()),
+            },
             block_beh_get_render_shape: MSig {
                 owner: cn.block_beh.clone(),
                 name: cs("getRenderShape"),
@@ -761,6 +1137,52 @@ impl MN<MSig> {
                     cn.interaction_result.sig.to_bytes(),
                 ),
             },
+            block_beh_use_item_on: MSig {
+                owner: cn.block_beh.clone(),
+                name: cs(b"useItemOn"),
+                sig: msig(
+                    [
+                        cn.item_stack.sig.to_bytes(),
+                        cn.block_state.sig.to_bytes(),
+                        cn.level.sig.to_bytes(),
+                        cn.block_pos.sig.to_bytes(),
+                        cn.player.sig.to_bytes(),
+                        cn.interaction_hand.sig.to_bytes(),
+                        cn.block_hit_result.sig.to_bytes(),
+                    ],
+                    cn.item_interaction_result.sig.to_bytes(),
+                ),
+            },
+            block_beh_rotate: MSig {
+                owner: cn.block_beh.clone(),
+                name: cs("rotate"),
+                sig: msig([cn.block_state.sig.to_bytes(), cn.rotation.sig.to_bytes()], cn.block_state.sig.to_bytes()),
+            },
+            block_beh_mirror: MSig {
+                owner: cn.block_beh.clone(),
+                name: cs("mirror"),
+                sig: msig([cn.block_state.sig.to_bytes(), cn.mirror.sig.to_bytes()], cn.block_state.sig.to_bytes()),
+            },
+            block_beh_get_fluid_state: MSig {
+                owner: cn.block_beh.clone(),
+                name: cs("getFluidState"),
+                sig: msig([cn.block_state.sig.to_bytes()], cn.fluid_state.sig.to_bytes()),
+            },
+            block_beh_update_shape: MSig {
+                owner: cn.block_beh.clone(),
+                name: cs("updateShape"),
+                sig: msig(
+                    [
+                        cn.block_state.sig.to_bytes(),
+                        cn.dir.sig.to_bytes(),
+                        cn.block_state.sig.to_bytes(),
+                        cn.level_access.sig.to_bytes(),
+                        cn.block_pos.sig.to_bytes(),
+                        cn.block_pos.sig.to_bytes(),
+                    ],
+                    cn.block_state.sig.to_bytes(),
+                ),
+            },
             block_item_init: MSig {
                 owner: cn.block_item.clone(),
                 name: cs("<init>"),
@@ -781,6 +1203,11 @@ impl MN<MSig> {
                 name: cs("getBlockEntity"),
                 sig: msig([cn.block_pos.sig.to_bytes()], cn.tile.sig.to_bytes()),
             },
+            block_getter_get_fluid_state: MSig {
+                owner: cn.block_getter.clone(),
+                name: cs("getFluidState"),
+                sig: msig([cn.block_pos.sig.to_bytes()], cn.fluid_state.sig.to_bytes()),
+            },
             vec3i_x: MSig { owner: cn.vec3i.clone(), name: cs("x"), sig: cs("I") },
             vec3i_y: MSig { owner: cn.vec3i.clone(), name: cs("y"), sig: cs("I") },
             vec3i_z: MSig { owner: cn.vec3i.clone(), name: cs("z"), sig: cs("I") },
@@ -790,6 +1217,7 @@ impl MN<MSig> {
             vec3d_z: MSig { owner: cn.vec3d.clone(), name: cs("z"), sig: cs("D") },
             block_pos_init: MSig { owner: cn.block_pos.clone(), name: cs("<init>"), sig: cs("(III)V") },
             block_state_get_block: MSig { owner: cn.block_state.clone(), name: cs("getBlock"), sig: msig([], cn.block.sig.to_bytes()) },
+            block_state_can_be_replaced: MSig { owner: cn.block_state.clone(), name: cs("canBeReplaced"), sig: cs("()Z") },
             block_state_get_visual_shape: MSig {
                 owner: cn.block_state.clone(),
                 name: cs("getVisualShape"),
@@ -798,6 +1226,43 @@ impl MN<MSig> {
                     cn.voxel_shape.sig.to_bytes(),
                 ),
             },
+            block_state_props_facing: MSig { owner: cn.block_state_props.clone(), name: cs("FACING"), sig: cn.dir_prop.sig.clone() },
+            block_state_props_waterlogged: MSig { owner: cn.block_state_props.clone(), name: cs("WATERLOGGED"), sig: cn.bool_prop.sig.clone() },
+            block_state_props_level: MSig { owner: cn.block_state_props.clone(), name: cs("LEVEL"), sig: cn.int_prop.sig.clone() },
+            state_def_builder_add: MSig {
+                owner: cn.state_def_builder.clone(),
+                name: cs("add"),
+                sig: msig([b"[", cn.prop.sig.to_bytes()], cn.state_def_builder.sig.to_bytes()),
+            },
+            state_holder_get_value: MSig {
+                owner: cn.state_holder.clone(),
+                name: cs("getValue"),
+                sig: msig([cn.prop.sig.to_bytes()], b"Ljava/lang/Comparable;"),
+            },
+            state_holder_set_value: MSig {
+                owner: cn.state_holder.clone(),
+                name: cs("setValue"),
+                sig: msig([cn.prop.sig.to_bytes(), b"Ljava/lang/Comparable;"], b"Ljava/lang/Object;"),
+            },
+            rotation_rotate: MSig { owner: cn.rotation.clone(), name: cs("rotate"), sig: msig([cn.dir.sig.to_bytes()], cn.dir.sig.to_bytes()) },
+            mirror_get_rotation: MSig {
+                owner: cn.mirror.clone(),
+                name: cs("getRotation"),
+                sig: msig([cn.dir.sig.to_bytes()], cn.rotation.sig.to_bytes()),
+            },
+            fluids_water: MSig { owner: cn.fluids.clone(), name: cs("WATER"), sig: cn.flowing_fluid.sig.clone() },
+            flowing_fluid_get_source: MSig {
+                owner: cn.flowing_fluid.clone(),
+                name: cs("getSource"),
+                sig: msig([B("Z")], cn.fluid_state.sig.to_bytes()),
+            },
+            fluid_get_tick_delay: MSig { owner: cn.fluid.clone(), name: cs("getTickDelay"), sig: msig([cn.level_reader.sig.to_bytes()], b"I") },
+            fluid_state_get_type: MSig { owner: cn.fluid_state.clone(), name: cs("getType"), sig: msig([], cn.fluid.sig.to_bytes()) },
+            level_access_schedule_fluid_tick: MSig {
+                owner: cn.level_access.clone(),
+                name: cs("scheduleTick"),
+                sig: msig([cn.block_pos.sig.to_bytes(), cn.fluid.sig.to_bytes(), b"I"], b"V"),
+            },
             blocks_fire: MSig { owner: cn.blocks.clone(), name: cs("FIRE"), sig: cn.block.sig.clone() },
             tile_supplier_create: MSig {
                 owner: cn.tile_supplier.clone(),
@@ -834,9 +1299,50 @@ impl MN<MSig> {
             tile_pos: MSig { owner: cn.tile.clone(), name: cs("worldPosition"), sig: cn.block_pos.sig.clone() },
             tile_set_removed: MSig { owner: cn.tile.clone(), name: cs("setRemoved"), sig: cs("()V") },
             tile_set_changed: MSig { owner: cn.tile.clone(), name: cs("setChanged"), sig: cs("()V") },
+            tile_set_block_state: MSig { owner: cn.tile.clone(), name: cs("setBlockState"), sig: msig([cn.block_state.sig.to_bytes()], b"V") },
             sound_type_metal: MSig { owner: cn.sound_type.clone(), name: cs("METAL"), sig: cn.sound_type.sig.clone() },
             item_get_desc_id: MSig { owner: cn.item.clone(), name: cs("getDescriptionId"), sig: cs("()Ljava/lang/String;") },
+            block_get_desc_id: MSig { owner: cn.block.clone(), name: cs("getDescriptionId"), sig: cs("()Ljava/lang/String;") },
             item_stack_init: MSig { owner: cn.item_stack.clone(), name: cs("<init>"), sig: msig([cn.item_like.sig.to_bytes(), b"I"], b"V") },
+            item_stack_get_item: MSig { owner: cn.item_stack.clone(), name: cs("getItem"), sig: msig([], cn.item.sig.to_bytes()) },
+            item_stack_empty: MSig { owner: cn.item_stack.clone(), name: cs("EMPTY"), sig: cn.item_stack.sig.clone() },
+            item_stack_is_empty: MSig { owner: cn.item_stack.clone(), name: cs("isEmpty"), sig: cs("()Z") },
+            item_stack_get_count: MSig { owner: cn.item_stack.clone(), name: cs("getCount"), sig: cs("()I") },
+            item_stack_copy: MSig { owner: cn.item_stack.clone(), name: cs("copy"), sig: msig([], cn.item_stack.sig.to_bytes()) },
+            item_stack_copy_with_count: MSig {
+                owner: cn.item_stack.clone(),
+                name: cs("copyWithCount"),
+                sig: msig([B("I")], cn.item_stack.sig.to_bytes()),
+            },
+            item_stack_get_component: MSig {
+                owner: cn.item_stack.clone(),
+                name: cs("get"),
+                sig: msig([cn.data_component_type.sig.to_bytes()], b"Ljava/lang/Object;"),
+            },
+            item_stack_set_component: MSig {
+                owner: cn.item_stack.clone(),
+                name: cs("set"),
+                sig: msig([cn.data_component_type.sig.to_bytes(), b"Ljava/lang/Object;"], b"Ljava/lang/Object;"),
+            },
+            item_append_hover_text: MSig {
+                owner: cn.item.clone(),
+                name: cs("appendHoverText"),
+                sig: msig(
+                    [cn.item_stack.sig.to_bytes(), cn.item_tooltip_ctx.sig.to_bytes(), b"Ljava/util/List;", cn.tooltip_flag.sig.to_bytes()],
+                    b"V",
+                ),
+            },
+            data_components_custom_data: MSig { owner: cn.data_components.clone(), name: cs("CUSTOM_DATA"), sig: cn.data_component_type.sig.clone() },
+            data_components_custom_name: MSig { owner: cn.data_components.clone(), name: cs("CUSTOM_NAME"), sig: cn.data_component_type.sig.clone() },
+            custom_data_of: MSig {
+                owner: cn.custom_data.clone(),
+                name: cs("of"),
+                sig: msig([cn.nbt_compound.sig.to_bytes()], cn.custom_data.sig.to_bytes()),
+            },
+            custom_data_copy_tag: MSig { owner: cn.custom_data.clone(), name: cs("copyTag"), sig: msig([], cn.nbt_compound.sig.to_bytes()) },
+            item_init: MSig { owner: cn.item.clone(), name: cs("<init>"), sig: msig([cn.item_props.sig.to_bytes()], b"V") },
+            item_props_init: MSig { owner: cn.item_props.clone(), name: cs("<init>"), sig: cs("()V") },
+            item_props_stacks_to: MSig { owner: cn.item_props.clone(), name: cs("stacksTo"), sig: msig([B("I")], cn.item_props.sig.to_bytes()) },
             creative_tab_items_gen_accept: MSig {
                 owner: cn.creative_tab_items_gen.clone(),
                 name: cs("accept"),
@@ -854,20 +1360,75 @@ impl MN<MSig> {
             nbt_compound_init: MSig { owner: cn.nbt_compound.clone(), name: cs("<init>"), sig: cs("()V") },
             nbt_compound_put_byte_array: MSig { owner: cn.nbt_compound.clone(), name: cs("putByteArray"), sig: cs("(Ljava/lang/String;[B)V") },
             nbt_compound_get_byte_array: MSig { owner: cn.nbt_compound.clone(), name: cs("getByteArray"), sig: cs("(Ljava/lang/String;)[B") },
+            nbt_compound_contains: MSig { owner: cn.nbt_compound.clone(), name: cs("contains"), sig: cs("(Ljava/lang/String;I)Z") },
+            nbt_compound_put: MSig {
+                owner: cn.nbt_compound.clone(),
+                name: cs("put"),
+                sig: msig([B("Ljava/lang/String;"), cn.nbt_tag.sig.to_bytes()], cn.nbt_tag.sig.to_bytes()),
+            },
+            nbt_compound_put_byte: MSig { owner: cn.nbt_compound.clone(), name: cs("putByte"), sig: cs("(Ljava/lang/String;B)V") },
+            nbt_compound_put_int: MSig { owner: cn.nbt_compound.clone(), name: cs("putInt"), sig: cs("(Ljava/lang/String;I)V") },
+            nbt_compound_get_int: MSig { owner: cn.nbt_compound.clone(), name: cs("getInt"), sig: cs("(Ljava/lang/String;)I") },
+            nbt_compound_put_bool: MSig { owner: cn.nbt_compound.clone(), name: cs("putBoolean"), sig: cs("(Ljava/lang/String;Z)V") },
+            nbt_compound_get_bool: MSig { owner: cn.nbt_compound.clone(), name: cs("getBoolean"), sig: cs("(Ljava/lang/String;)Z") },
+            nbt_compound_put_float: MSig { owner: cn.nbt_compound.clone(), name: cs("putFloat"), sig: cs("(Ljava/lang/String;F)V") },
+            nbt_compound_get_float: MSig { owner: cn.nbt_compound.clone(), name: cs("getFloat"), sig: cs("(Ljava/lang/String;)F") },
+            nbt_compound_put_long: MSig { owner: cn.nbt_compound.clone(), name: cs("putLong"), sig: cs("(Ljava/lang/String;J)V") },
+            nbt_compound_get_long: MSig { owner: cn.nbt_compound.clone(), name: cs("getLong"), sig: cs("(Ljava/lang/String;)J") },
+            nbt_compound_get_list: MSig {
+                owner: cn.nbt_compound.clone(),
+                name: cs("getList"),
+                sig: msig([B("Ljava/lang/String;I")], cn.nbt_list.sig.to_bytes()),
+            },
+            nbt_compound_get_compound: MSig {
+                owner: cn.nbt_compound.clone(),
+                name: cs("getCompound"),
+                sig: msig([B("Ljava/lang/String;")], cn.nbt_compound.sig.to_bytes()),
+            },
+            nbt_compound_put_string: MSig {
+                owner: cn.nbt_compound.clone(),
+                name: cs("putString"),
+                sig: cs("(Ljava/lang/String;Ljava/lang/String;)V"),
+            },
+            nbt_compound_get_string: MSig {
+                owner: cn.nbt_compound.clone(),
+                name: cs("getString"),
+                sig: cs("(Ljava/lang/String;)Ljava/lang/String;"),
+            },
+            nbt_compound_put_uuid: MSig { owner: cn.nbt_compound.clone(), name: cs("putUUID"), sig: cs("(Ljava/lang/String;Ljava/util/UUID;)V") },
+            nbt_compound_has_uuid: MSig { owner: cn.nbt_compound.clone(), name: cs("hasUUID"), sig: cs("(Ljava/lang/String;)Z") },
+            nbt_compound_get_uuid: MSig { owner: cn.nbt_compound.clone(), name: cs("getUUID"), sig: cs("(Ljava/lang/String;)Ljava/util/UUID;") },
+            nbt_list_init: MSig { owner: cn.nbt_list.clone(), name: cs("<init>"), sig: cs("()V") },
+            nbt_list_add: MSig { owner: cn.nbt_list.clone(), name: cs("add"), sig: cs("(Ljava/lang/Object;)Z") },
+            nbt_list_size: MSig { owner: cn.nbt_list.clone(), name: cs("size"), sig: cs("()I") },
+            nbt_list_get_string: MSig { owner: cn.nbt_list.clone(), name: cs("getString"), sig: cs("(I)Ljava/lang/String;") },
+            nbt_string_value_of: MSig {
+                owner: cn.nbt_string.clone(),
+                name: cs("valueOf"),
+                sig: msig([B("Ljava/lang/String;")], cn.nbt_string.sig.to_bytes()),
+            },
             use_on_ctx_get_level: MSig { owner: cn.use_on_ctx.clone(), name: cs("getLevel"), sig: msig([], cn.level.sig.to_bytes()) },
             use_on_ctx_get_clicked_pos: MSig { owner: cn.use_on_ctx.clone(), name: cs("getClickedPos"), sig: msig([], cn.block_pos.sig.to_bytes()) },
             use_on_ctx_get_clicked_face: MSig { owner: cn.use_on_ctx.clone(), name: cs("getClickedFace"), sig: msig([], cn.dir.sig.to_bytes()) },
+            use_on_ctx_get_item: MSig { owner: cn.use_on_ctx.clone(), name: cs("getItemInHand"), sig: msig([], cn.item_stack.sig.to_bytes()) },
+            use_on_ctx_get_player: MSig { owner: cn.use_on_ctx.clone(), name: cs("getPlayer"), sig: msig([], cn.player.sig.to_bytes()) },
             dir_3d_data: MSig { owner: cn.dir.clone(), name: cs("data3d"), sig: cs("I") },
             dir_by_3d_data: MSig {
                 owner: cn.dir.clone(),
                 name: cs("BY_3D_DATA"),
                 sig: cs(Vec::from_iter(b"[".iter().chain(cn.dir.sig.as_bytes()).copied())),
             },
+            level_remove_block: MSig { owner: cn.level.clone(), name: cs("removeBlock"), sig: msig([cn.block_pos.sig.to_bytes(), b"Z"], b"Z") },
             level_set_block_and_update: MSig {
                 owner: cn.level.clone(),
                 name: cs("setBlockAndUpdate"),
                 sig: msig([cn.block_pos.sig.to_bytes(), cn.block_state.sig.to_bytes()], b"Z"),
             },
+            level_set_block: MSig {
+                owner: cn.level.clone(),
+                name: cs("setBlock"),
+                sig: msig([cn.block_pos.sig.to_bytes(), cn.block_state.sig.to_bytes(), b"I"], b"Z"),
+            },
             level_update_neighbors_for_out_signal: MSig {
                 owner: cn.level.clone(),
                 name: cs("updateNeighbourForOutputSignal"),
@@ -876,6 +1437,7 @@ impl MN<MSig> {
             level_is_client: MSig { owner: cn.level.clone(), name: cs("isClientSide"), sig: cs("Z") },
             level_get_chunk_source: MSig { owner: cn.level.clone(), name: cs("getChunkSource"), sig: msig([], cn.chunk_source.sig.to_bytes()) },
             level_is_outside_build_height: MSig { owner: cn.level.clone(), name: cs("isOutsideBuildHeight"), sig: cs("(I)Z") },
+            level_get_game_time: MSig { owner: cn.level.clone(), name: cs("getGameTime"), sig: cs("()J") },
             menu_provider_create_menu: MSig {
                 owner: cn.menu_provider.clone(),
                 name: cs("createMenu"),
@@ -891,16 +1453,27 @@ impl MN<MSig> {
                 name: cs("translatable"),
                 sig: msig([B("Ljava/lang/String;")], cn.chat_mutable_component.sig.to_bytes()),
             },
+            chat_component_translatable_args: MSig {
+                owner: cn.chat_component.clone(),
+                name: cs("translatable"),
+                sig: msig([B("Ljava/lang/String;[Ljava/lang/Object;")], cn.chat_mutable_component.sig.to_bytes()),
+            },
             chat_component_literal: MSig {
                 owner: cn.chat_component.clone(),
                 name: cs("literal"),
                 sig: msig([B("Ljava/lang/String;")], cn.chat_mutable_component.sig.to_bytes()),
             },
+            chat_component_get_string: MSig { owner: cn.chat_component.clone(), name: cs("getString"), sig: cs("()Ljava/lang/String;") },
             chat_component_to_formatted: MSig {
                 owner: cn.chat_component.clone(),
                 name: cs("getVisualOrderText"),
                 sig: msig([], cn.formatted_char_seq.sig.to_bytes()),
             },
+            chat_mutable_component_with_style: MSig {
+                owner: cn.chat_mutable_component.clone(),
+                name: cs("withStyle"),
+                sig: msig([cn.chat_fmt.sig.to_bytes()], cn.chat_mutable_component.sig.to_bytes()),
+            },
             chat_fmt_from_code: MSig { owner: cn.chat_fmt.clone(), name: cs("getByCode"), sig: msig([B("C")], cn.chat_fmt.sig.to_bytes()) },
             chat_fmt_color: MSig { owner: cn.chat_fmt.clone(), name: cs("color"), sig: cs("Ljava/lang/Integer;") },
             friendly_byte_buf_read_byte_array: MSig { owner: cn.friendly_byte_buf.clone(), name: cs("readByteArray"), sig: cs("()[B") },
@@ -910,6 +1483,13 @@ impl MN<MSig> {
                 sig: msig([B("[B")], cn.friendly_byte_buf.sig.to_bytes()),
             },
             inventory_player: MSig { owner: cn.inventory.clone(), name: cs("player"), sig: cn.player.sig.clone() },
+            inventory_place_back: MSig {
+                owner: cn.inventory.clone(),
+                name: cs("placeItemBackInInventory"),
+                sig: msig([cn.item_stack.sig.to_bytes()], b"V"),
+            },
+            player_get_inventory: MSig { owner: cn.player.clone(), name: cs("getInventory"), sig: msig([], cn.inventory.sig.to_bytes()) },
+            entity_is_shift_key_down: MSig { owner: cn.entity.clone(), name: cs("isShiftKeyDown"), sig: cs("()Z") },
             entity_level: MSig { owner: cn.entity.clone(), name: cs("level"), sig: msig([], cn.level.sig.to_bytes()) },
             entity_y_rot: MSig { owner: cn.entity.clone(), name: cs("yRot"), sig: cs("F") },
             container_still_valid: MSig {
@@ -920,6 +1500,22 @@ impl MN<MSig> {
             player_profile: MSig { owner: cn.player.clone(), name: cs("gameProfile"), sig: cn.game_profile.sig.clone() },
             player_container_menu: MSig { owner: cn.player.clone(), name: cs("containerMenu"), sig: cn.container_menu.sig.clone() },
             game_profile_get_name: MSig { owner: cn.game_profile.clone(), name: cs("getName"), sig: cs("()Ljava/lang/String;") },
+            entity_get_uuid: MSig { owner: cn.entity.clone(), name: cs("getUUID"), sig: cs("()Ljava/util/UUID;") },
+            entity_get_team: MSig { owner: cn.entity.clone(), name: cs("getTeam"), sig: msig([], cn.player_team.sig.to_bytes()) },
+            entity_get_server: MSig { owner: cn.entity.clone(), name: cs("getServer"), sig: msig([], cn.mc_server.sig.to_bytes()) },
+            mc_server_get_profile_cache: MSig {
+                owner: cn.mc_server.clone(),
+                name: cs("getProfileCache"),
+                sig: msig([], cn.profile_cache.sig.to_bytes()),
+            },
+            profile_cache_get: MSig { owner: cn.profile_cache.clone(), name: cs("get"), sig: cs("(Ljava/util/UUID;)Ljava/util/Optional;") },
+            player_team_get_players: MSig { owner: cn.player_team.clone(), name: cs("getPlayers"), sig: cs("()Ljava/util/Collection;") },
+            player_has_permissions: MSig { owner: cn.player.clone(), name: cs("hasPermissions"), sig: cs("(I)Z") },
+            player_display_client_message: MSig {
+                owner: cn.player.clone(),
+                name: cs("displayClientMessage"),
+                sig: msig([cn.chat_component.sig.to_bytes(), b"Z"], b"V"),
+            },
             server_chunk_cache_block_changed: MSig {
                 owner: cn.server_chunk_cache.clone(),
                 name: cs("blockChanged"),
@@ -941,6 +1537,17 @@ impl MN<MSig> {
             block_hit_result_pos: MSig { owner: cn.block_hit_result.clone(), name: cs("location"), sig: cn.vec3d.sig.clone() },
             block_hit_result_miss: MSig { owner: cn.block_hit_result.clone(), name: cs("miss"), sig: cs("Z") },
             block_hit_result_dir: MSig { owner: cn.block_hit_result.clone(), name: cs("direction"), sig: cn.dir.sig.clone() },
+            block_hit_result_block_pos: MSig { owner: cn.block_hit_result.clone(), name: cs("blockPos"), sig: cn.block_pos.sig.clone() },
+            living_entity_main_hand_item: MSig {
+                owner: cn.living_entity.clone(),
+                name: cs("getMainHandItem"),
+                sig: msig([], cn.item_stack.sig.to_bytes()),
+            },
+            living_entity_offhand_item: MSig {
+                owner: cn.living_entity.clone(),
+                name: cs("getOffhandItem"),
+                sig: msig([], cn.item_stack.sig.to_bytes()),
+            },
             chunk_access_pos: MSig { owner: cn.chunk_access.clone(), name: cs("chunkPos"), sig: cn.chunk_pos.sig.clone() },
             level_chunk_set_block_state: MSig {
                 owner: cn.level_chunk.clone(),
@@ -961,6 +1568,13 @@ impl MN<MSig> {
             reg_key_block: MSig { owner: cn.registries.clone(), name: cs("BLOCK"), sig: cn.resource_key.sig.clone() },
             reg_key_tile_type: MSig { owner: cn.registries.clone(), name: cs("BLOCK_ENTITY_TYPE"), sig: cn.resource_key.sig.clone() },
             reg_key_menu: MSig { owner: cn.registries.clone(), name: cs("MENU"), sig: cn.resource_key.sig.clone() },
+            reg_key_item: MSig { owner: cn.registries.clone(), name: cs("ITEM"), sig: cn.resource_key.sig.clone() },
+            loot_builder_get_optional: MSig {
+                owner: cn.loot_builder.clone(),
+                name: cs("getOptionalParameter"),
+                sig: msig([cn.loot_ctx_param.sig.to_bytes()], b"Ljava/lang/Object;"),
+            },
+            loot_ctx_params_tile: MSig { owner: cn.loot_ctx_params.clone(), name: cs("BLOCK_ENTITY"), sig: cn.loot_ctx_param.sig.clone() },
             custom_payload_type: MSig { owner: cn.custom_payload.clone(), name: cs("type"), sig: msig([], cn.custom_payload_type.sig.to_bytes()) },
             custom_payload_type_init: MSig {
                 owner: cn.custom_payload_type.clone(),
@@ -980,9 +1594,25 @@ impl MN<MSig> {
                 name: cs("create"),
                 sig: msig([cn.tile_renderer_provider_ctx.sig.to_bytes()], cn.tile_renderer.sig.to_bytes()),
             },
+            item_renderer_render: MSig {
+                owner: cn.item_renderer.clone(),
+                name: cs("renderByItem"),
+                sig: msig(
+                    [
+                        cn.item_stack.sig.to_bytes(),
+                        cn.item_display_ctx.sig.to_bytes(),
+                        cn.pose_stack.sig.to_bytes(),
+                        cn.multi_buffer_source.sig.to_bytes(),
+                        b"II",
+                    ],
+                    b"V",
+                ),
+            },
             pose_pose: MSig { owner: cn.pose.clone(), name: cs("pose"), sig: cn.matrix4f.sig.clone() },
             pose_stack_last: MSig { owner: cn.pose_stack.clone(), name: cs("last"), sig: msig([], cn.pose.sig.to_bytes()) },
             matrix4fc_read: MSig { owner: cn.matrix4fc.clone(), name: cs("getToAddress"), sig: msig([B("J")], cn.matrix4fc.sig.to_bytes()) },
+            matrix4f_init: MSig { owner: cn.matrix4f.clone(), name: cs("<init>"), sig: cs("()V") },
+            matrix4f_write: MSig { owner: cn.matrix4f.clone(), name: cs("setFromAddress"), sig: msig([B("J")], cn.matrix4f.sig.to_bytes()) },
             atlas_loc: MSig { owner: cn.atlas.clone(), name: cs("location"), sig: msig([], cn.resource_loc.sig.to_bytes()) },
             atlas_loc_blocks: MSig { owner: cn.atlas.clone(), name: cs("LOCATION_BLOCKS"), sig: cn.resource_loc.sig.clone() },
             atlas_get_sprite: MSig {
@@ -995,6 +1625,11 @@ impl MN<MSig> {
             sprite_u1: MSig { owner: cn.sprite.clone(), name: cs("u1"), sig: cs("F") },
             sprite_v1: MSig { owner: cn.sprite.clone(), name: cs("v1"), sig: cs("F") },
             sheets_solid: MSig { owner: cn.sheets.clone(), name: cs("solidBlockSheet"), sig: msig([], cn.render_type.sig.to_bytes()) },
+            sheets_translucent: MSig {
+                owner: cn.sheets.clone(),
+                name: cs("translucentCullBlockSheet"),
+                sig: msig([], cn.render_type.sig.to_bytes()),
+            },
             multi_buffer_source_get_buffer: MSig {
                 owner: cn.multi_buffer_source.clone(),
                 name: cs("getBuffer"),
@@ -1024,6 +1659,26 @@ impl MN<MSig> {
                 sig: msig([cn.player.sig.to_bytes(), b"I"], cn.item_stack.sig.to_bytes()),
             },
             container_menu_id: MSig { owner: cn.container_menu.clone(), name: cs("containerId"), sig: cs("I") },
+            container_menu_add_slot: MSig {
+                owner: cn.container_menu.clone(),
+                name: cs("addSlot"),
+                sig: msig([cn.slot.sig.to_bytes()], cn.slot.sig.to_bytes()),
+            },
+            container_menu_get_slot: MSig { owner: cn.container_menu.clone(), name: cs("getSlot"), sig: msig([B("I")], cn.slot.sig.to_bytes()) },
+            container_menu_move_item_stack_to: MSig {
+                owner: cn.container_menu.clone(),
+                name: cs("moveItemStackTo"),
+                sig: msig([cn.item_stack.sig.to_bytes(), b"IIZ"], b"Z"),
+            },
+            slot_init: MSig { owner: cn.slot.clone(), name: cs("<init>"), sig: msig([cn.container.sig.to_bytes(), b"III"], b"V") },
+            slot_has_item: MSig { owner: cn.slot.clone(), name: cs("hasItem"), sig: cs("()Z") },
+            slot_get_item: MSig { owner: cn.slot.clone(), name: cs("getItem"), sig: msig([], cn.item_stack.sig.to_bytes()) },
+            slot_set_by_player: MSig { owner: cn.slot.clone(), name: cs("setByPlayer"), sig: msig([cn.item_stack.sig.to_bytes()], b"V") },
+            slot_on_take: MSig {
+                owner: cn.slot.clone(),
+                name: cs("onTake"),
+                sig: msig([cn.player.sig.to_bytes(), cn.item_stack.sig.to_bytes()], b"V"),
+            },
             menu_screens_reg: MSig {
                 owner: cn.menu_screens.clone(),
                 name: cs("register"),
@@ -1046,6 +1701,24 @@ impl MN<MSig> {
                 name: cs("renderBackground"),
                 sig: msig([cn.gui_graphics.sig.to_bytes(), b"IIF"], b"V"),
             },
+            screen_has_shift_down: MSig { owner: cn.screen.clone(), name: cs("hasShiftDown"), sig: cs("()Z") },
+            screen_has_control_down: MSig { owner: cn.screen.clone(), name: cs("hasControlDown"), sig: cs("()Z") },
+            screen_update_narration: MSig {
+                owner: cn.screen.clone(),
+                name: cs("updateNarration"),
+                sig: msig([cn.narration_element_output.sig.to_bytes()], b"V"),
+            },
+            narration_output_add: MSig {
+                owner: cn.narration_element_output.clone(),
+                name: cs("add"),
+                sig: msig([cn.narrated_element_type.sig.to_bytes(), cn.chat_component.sig.to_bytes()], b"V"),
+            },
+            narrated_element_type_position: MSig {
+                owner: cn.narrated_element_type.clone(),
+                name: cs("POSITION"),
+                sig: cn.narrated_element_type.sig.clone(),
+            },
+            narrated_element_type_hint: MSig { owner: cn.narrated_element_type.clone(), name: cs("HINT"), sig: cn.narrated_element_type.sig.clone() },
             container_screen_init: MSig {
                 owner: cn.container_screen.clone(),
                 name: cs("<init>"),
@@ -1072,12 +1745,21 @@ impl MN<MSig> {
             container_screen_mouse_clicked: MSig { owner: cn.container_screen.clone(), name: cs("mouseClicked"), sig: cs("(DDI)Z") },
             container_screen_mouse_dragged: MSig { owner: cn.container_screen.clone(), name: cs("mouseDragged"), sig: cs("(DDIDD)Z") },
             container_screen_mouse_released: MSig { owner: cn.container_screen.clone(), name: cs("mouseReleased"), sig: cs("(DDI)Z") },
+            container_screen_mouse_scrolled: MSig { owner: cn.container_screen.clone(), name: cs("mouseScrolled"), sig: cs("(DDDD)Z") },
+            container_screen_has_clicked_outside: MSig { owner: cn.container_screen.clone(), name: cs("hasClickedOutside"), sig: cs("(DDIII)Z") },
+            container_screen_key_pressed: MSig { owner: cn.container_screen.clone(), name: cs("keyPressed"), sig: cs("(III)Z") },
+            container_screen_char_typed: MSig { owner: cn.container_screen.clone(), name: cs("charTyped"), sig: cs("(CI)Z") },
             gui_graphics_draw_formatted: MSig {
                 owner: cn.gui_graphics.clone(),
                 name: cs("drawString"),
                 sig: msig([cn.font.sig.to_bytes(), cn.formatted_char_seq.sig.to_bytes(), b"IIIZ"], b"I"),
             },
             gui_graphics_pose: MSig { owner: cn.gui_graphics.clone(), name: cs("pose"), sig: cn.pose_stack.sig.clone() },
+            gui_graphics_buffer_source: MSig {
+                owner: cn.gui_graphics.clone(),
+                name: cs("bufferSource"),
+                sig: msig([], cn.buffer_source.sig.to_bytes()),
+            },
             render_sys_set_shader: MSig { owner: cn.render_sys.clone(), name: cs(b"setShader"), sig: cs("(Ljava/util/function/Supplier;)V") },
             render_sys_enable_blend: MSig { owner: cn.render_sys.clone(), name: cs(b"enableBlend"), sig: cs("()V") },
             render_sys_enable_cull: MSig { owner: cn.render_sys.clone(), name: cs(b"enableCull"), sig: cs("()V") },
@@ -1102,16 +1784,61 @@ impl MN<MSig> {
             mc_get_sound_mgr: MSig { owner: cn.mc.clone(), name: cs("getSoundManager"), sig: msig([], cn.sound_mgr.sig.to_bytes()) },
             mc_clear_level: MSig { owner: cn.mc.clone(), name: cs("clearClientLevel"), sig: msig([cn.screen.sig.to_bytes()], b"V") },
             mc_player: MSig { owner: cn.mc.clone(), name: cs("player"), sig: cn.local_player.sig.clone() },
+            mc_level_renderer: MSig { owner: cn.mc.clone(), name: cs("levelRenderer"), sig: cn.level_renderer.sig.clone() },
+            mc_hit_result: MSig { owner: cn.mc.clone(), name: cs("hitResult"), sig: cn.hit_result.sig.clone() },
+            key_mapping_init: MSig { owner: cn.key_mapping.clone(), name: cs("<init>"), sig: cs("(Ljava/lang/String;ILjava/lang/String;)V") },
+            key_mapping_consume_click: MSig { owner: cn.key_mapping.clone(), name: cs("consumeClick"), sig: cs("()Z") },
+            model_res_loc_standalone: MSig {
+                owner: cn.model_res_loc.clone(),
+                name: cs("standalone"),
+                sig: msig([cn.resource_loc.sig.to_bytes()], cn.model_res_loc.sig.to_bytes()),
+            },
+            model_res_loc_inventory: MSig {
+                owner: cn.model_res_loc.clone(),
+                name: cs("inventory"),
+                sig: msig([cn.resource_loc.sig.to_bytes()], cn.model_res_loc.sig.to_bytes()),
+            },
             window_get_gui_scale: MSig { owner: cn.window.clone(), name: cs("getGuiScale"), sig: cs("()D") },
             font_width: MSig { owner: cn.font.clone(), name: cs("width"), sig: msig([cn.formatted_char_seq.sig.to_bytes()], b"I") },
             sound_mgr_play: MSig { owner: cn.sound_mgr.clone(), name: cs("play"), sig: msig([cn.sound_inst.sig.to_bytes()], b"V") },
+            sound_mgr_stop: MSig { owner: cn.sound_mgr.clone(), name: cs("stop"), sig: msig([cn.sound_inst.sig.to_bytes()], b"V") },
             simple_sound_inst_for_ui_holder: MSig {
                 owner: cn.simple_sound_inst.clone(),
                 name: cs("forUI"),
                 sig: msig([cn.holder.sig.to_bytes(), b"F"], cn.simple_sound_inst.sig.to_bytes()),
             },
+            simple_sound_inst_init: MSig {
+                owner: cn.simple_sound_inst.clone(),
+                name: cs("<init>"),
+                sig: msig(
+                    [
+                        cn.resource_loc.sig.to_bytes(),
+                        cn.sound_source.sig.to_bytes(),
+                        b"FF",
+                        cn.random_source.sig.to_bytes(),
+                        b"ZI",
+                        cn.sound_attenuation.sig.to_bytes(),
+                        b"DDDZ",
+                    ],
+                    b"V",
+                ),
+            },
+            sound_inst_create_random: MSig {
+                owner: cn.sound_inst.clone(),
+                name: cs("createUnseededRandom"),
+                sig: msig([], cn.random_source.sig.to_bytes()),
+            },
+            sound_source_blocks: MSig { owner: cn.sound_source.clone(), name: cs("BLOCKS"), sig: cn.sound_source.sig.clone() },
+            sound_attenuation_linear: MSig { owner: cn.sound_attenuation.clone(), name: cs("LINEAR"), sig: cn.sound_attenuation.sig.clone() },
             render_type_lightning: MSig { owner: cn.render_type.clone(), name: cs("LIGHTNING"), sig: cn.render_type.sig.clone() },
+            render_type_lines: MSig { owner: cn.render_type.clone(), name: cs("LINES"), sig: cn.render_type.sig.clone() },
+            level_renderer_render_line_box: MSig {
+                owner: cn.level_renderer.clone(),
+                name: cs("renderLineBox"),
+                sig: msig([cn.pose_stack.sig.to_bytes(), cn.vertex_consumer.sig.to_bytes(), b"DDDDDDFFFF"], b"V"),
+            },
             level_renderer_buffers: MSig { owner: cn.level_renderer.clone(), name: cs("renderBuffers"), sig: cn.render_buffers.sig.clone() },
+            level_renderer_ticks: MSig { owner: cn.level_renderer.clone(), name: cs("ticks"), sig: cs("I") },
             render_buffers_buffer_source: MSig { owner: cn.render_buffers.clone(), name: cs("bufferSource"), sig: cn.buffer_source.sig.clone() },
             camera_pos: MSig { owner: cn.camera.clone(), name: cs("position"), sig: cn.vec3d.sig.clone() },
             buffer_uploader_draw: MSig {
@@ -1132,6 +1859,7 @@ pub struct MV {
     pub block_beh_props_strength: usize,
     pub block_beh_props_dyn_shape: usize,
     pub block_beh_props_sound: usize,
+    pub block_beh_props_light_level: usize,
     pub block_item: GlobalRef<'static>,
     pub block_item_init: usize,
     pub block_item_place_block: usize,
@@ -1149,6 +1877,14 @@ pub struct MV {
     pub block_pos_init: usize,
     pub block_state_get_block: usize,
     pub block_state_get_visual_shape: usize,
+    pub block_state_can_be_replaced: usize,
+    pub block_state_props_facing: GlobalRef<'static>,
+    pub prop: GlobalRef<'static>,
+    pub state_def_builder_add: usize,
+    pub state_holder_get_value: usize,
+    pub state_holder_set_value: usize,
+    pub rotation_rotate: usize,
+    pub mirror_get_rotation: usize,
     pub blocks_fire: GlobalRef<'static>,
     pub tile_type: GlobalRef<'static>,
     pub tile_type_init: usize,
@@ -1160,11 +1896,31 @@ pub struct MV {
     pub tile_pos: usize,
     pub tile_set_removed: usize,
     pub tile_set_changed: usize,
+    pub tile_set_block_state: usize,
     pub sound_type_metal: GlobalRef<'static>,
     pub item: GlobalRef<'static>,
     pub item_get_desc_id: usize,
+    pub block_get_desc_id: usize,
     pub item_stack: GlobalRef<'static>,
     pub item_stack_init: usize,
+    pub item_stack_get_item: usize,
+    pub item_stack_empty: GlobalRef<'static>,
+    pub item_stack_is_empty: usize,
+    pub item_stack_get_count: usize,
+    pub item_stack_copy: usize,
+    pub item_stack_copy_with_count: usize,
+    pub item_stack_get_component: usize,
+    pub item_stack_set_component: usize,
+    pub data_components_custom_data: GlobalRef<'static>,
+    pub data_components_custom_name: GlobalRef<'static>,
+    pub custom_data: GlobalRef<'static>,
+    pub custom_data_of: usize,
+    pub custom_data_copy_tag: usize,
+    pub list_add: usize,
+    pub item_init: usize,
+    pub item_props: GlobalRef<'static>,
+    pub item_props_init: usize,
+    pub item_props_stacks_to: usize,
     pub render_shape_tile: GlobalRef<'static>,
     pub resource_loc: GlobalRef<'static>,
     pub resource_loc_init: usize,
@@ -1174,32 +1930,79 @@ pub struct MV {
     pub nbt_compound_init: usize,
     pub nbt_compound_put_byte_array: usize,
     pub nbt_compound_get_byte_array: usize,
+    pub nbt_compound_contains: usize,
+    pub nbt_compound_put: usize,
+    pub nbt_compound_put_byte: usize,
+    pub nbt_compound_put_int: usize,
+    pub nbt_compound_get_int: usize,
+    pub nbt_compound_put_bool: usize,
+    pub nbt_compound_get_bool: usize,
+    pub nbt_compound_put_float: usize,
+    pub nbt_compound_get_float: usize,
+    pub nbt_compound_put_long: usize,
+    pub nbt_compound_get_long: usize,
+    pub nbt_compound_get_list: usize,
+    pub nbt_compound_get_compound: usize,
+    pub nbt_compound_put_string: usize,
+    pub nbt_compound_get_string: usize,
+    pub nbt_compound_put_uuid: usize,
+    pub nbt_compound_has_uuid: usize,
+    pub nbt_compound_get_uuid: usize,
+    pub nbt_list: GlobalRef<'static>,
+    pub nbt_list_init: usize,
+    pub nbt_list_add: usize,
+    pub nbt_list_size: usize,
+    pub nbt_list_get_string: usize,
+    pub nbt_string: GlobalRef<'static>,
+    pub nbt_string_value_of: usize,
     pub use_on_ctx_get_level: usize,
     pub use_on_ctx_get_clicked_pos: usize,
     pub use_on_ctx_get_clicked_face: usize,
+    pub use_on_ctx_get_item: usize,
+    pub use_on_ctx_get_player: usize,
     pub dir_3d_data: usize,
     pub dir_by_3d_data: GlobalRef<'static>,
     pub level_set_block_and_update: usize,
+    pub level_set_block: usize,
+    pub level_remove_block: usize,
     pub level_update_neighbors_for_out_signal: usize,
     pub level_is_client: usize,
     pub level_get_chunk_source: usize,
     pub level_is_outside_build_height: usize,
+    pub level_get_game_time: usize,
     pub friendly_byte_buf_read_byte_array: usize,
     pub friendly_byte_buf_write_byte_array: usize,
     pub container_menu_init: usize,
     pub container_menu_id: usize,
+    pub container_menu_add_slot: usize,
+    pub container_menu_get_slot: usize,
+    pub container_menu_move_item_stack_to: usize,
+    pub slot: GlobalRef<'static>,
+    pub slot_init: usize,
+    pub slot_has_item: usize,
+    pub slot_get_item: usize,
+    pub slot_set_by_player: usize,
+    pub slot_on_take: usize,
     pub chat_component: GlobalRef<'static>,
     pub chat_component_translatable: usize,
+    pub chat_component_translatable_args: usize,
     pub chat_component_literal: usize,
+    pub chat_component_get_string: usize,
     pub chat_component_to_formatted: usize,
+    pub chat_mutable_component_with_style: usize,
     pub chat_fmt: GlobalRef<'static>,
     pub chat_fmt_from_code: usize,
     pub chat_fmt_color: usize,
     pub interaction_result_pass: GlobalRef<'static>,
     pub interaction_result_success: GlobalRef<'static>,
     pub interaction_result_consume: GlobalRef<'static>,
+    pub item_interaction_result_success: GlobalRef<'static>,
+    pub item_interaction_result_pass: GlobalRef<'static>,
     pub server_player: GlobalRef<'static>,
     pub inventory_player: usize,
+    pub inventory_place_back: usize,
+    pub player_get_inventory: usize,
+    pub entity_is_shift_key_down: usize,
     pub entity_level: usize,
     pub entity_y_rot: usize,
     pub container: GlobalRef<'static>,
@@ -1207,15 +2010,33 @@ pub struct MV {
     pub player_profile: usize,
     pub player_container_menu: usize,
     pub game_profile_get_name: usize,
+    pub entity_get_uuid: usize,
+    pub entity_get_team: usize,
+    pub entity_get_server: usize,
+    pub mc_server_get_profile_cache: usize,
+    pub profile_cache_get: usize,
+    pub player_team_get_players: usize,
+    pub player_has_permissions: usize,
+    pub player_display_client_message: usize,
+    pub uuid: GlobalRef<'static>,
+    pub uuid_init: usize,
+    pub uuid_msb: usize,
+    pub uuid_lsb: usize,
+    pub collection_contains: usize,
+    pub optional_or_else: usize,
     pub sound_evts_ui_btn_click: GlobalRef<'static>,
     pub server_chunk_cache_block_changed: usize,
     pub chunk_pos_x: usize,
     pub chunk_pos_z: usize,
     pub collision_ctx_empty: GlobalRef<'static>,
     pub voxel_shape_clip: usize,
+    pub block_hit_result: GlobalRef<'static>,
     pub block_hit_result_pos: usize,
     pub block_hit_result_miss: usize,
     pub block_hit_result_dir: usize,
+    pub block_hit_result_block_pos: usize,
+    pub living_entity_main_hand_item: usize,
+    pub living_entity_offhand_item: usize,
     pub chunk_access_pos: usize,
     pub level_chunk: GlobalRef<'static>,
     pub chunk_source_get_chunk_now: usize,
@@ -1224,7 +2045,23 @@ pub struct MV {
     pub reg_key_block: GlobalRef<'static>,
     pub reg_key_tile_type: GlobalRef<'static>,
     pub reg_key_menu: GlobalRef<'static>,
+    pub reg_key_item: GlobalRef<'static>,
+    pub loot_builder_get_optional: usize,
+    pub loot_ctx_params_tile: GlobalRef<'static>,
     pub custom_payload_type: GlobalRef<'static>,
+    pub block: GlobalRef<'static>,
+    pub block_beh_get_fluid_state: usize,
+    pub block_beh_update_shape: usize,
+    pub block_getter_get_fluid_state: usize,
+    pub block_state_props_waterlogged: GlobalRef<'static>,
+    pub block_state_props_level: GlobalRef<'static>,
+    pub fluids_water: GlobalRef<'static>,
+    pub fluids_water_source: GlobalRef<'static>,
+    pub fluid_get_tick_delay: usize,
+    pub fluid_state_get_type: usize,
+    pub level_access_schedule_fluid_tick: usize,
+    pub integer: GlobalRef<'static>,
+    pub integer_value_of: usize,
     pub custom_payload_type_init: usize,
     pub client: Option<MVC>,
 }
@@ -1233,6 +2070,9 @@ pub struct MVC {
     pub pose_pose: usize,
     pub pose_stack_last: usize,
     pub matrix4fc_read: usize,
+    pub matrix4f: GlobalRef<'static>,
+    pub matrix4f_init: usize,
+    pub matrix4f_write: usize,
     pub atlas_loc: usize,
     pub atlas_loc_blocks: GlobalRef<'static>,
     pub atlas_get_sprite: usize,
@@ -1252,6 +2092,13 @@ pub struct MVC {
     pub screen_width: usize,
     pub screen_height: usize,
     pub screen_render_background: usize,
+    pub screen: GlobalRef<'static>,
+    pub screen_has_shift_down: usize,
+    pub screen_has_control_down: usize,
+    pub screen_update_narration: usize,
+    pub narration_output_add: usize,
+    pub narrated_element_type_position: GlobalRef<'static>,
+    pub narrated_element_type_hint: GlobalRef<'static>,
     pub container_screen: GlobalRef<'static>,
     pub container_screen_init: usize,
     pub container_screen_img_width: usize,
@@ -1264,8 +2111,12 @@ pub struct MVC {
     pub container_screen_mouse_clicked: usize,
     pub container_screen_mouse_dragged: usize,
     pub container_screen_mouse_released: usize,
+    pub container_screen_mouse_scrolled: usize,
+    pub container_screen_key_pressed: usize,
+    pub container_screen_char_typed: usize,
     pub gui_graphics_draw_formatted: usize,
     pub gui_graphics_pose: usize,
+    pub gui_graphics_buffer_source: usize,
     pub render_sys: GlobalRef<'static>,
     pub render_sys_set_shader: usize,
     pub render_sys_enable_blend: usize,
@@ -1287,11 +2138,29 @@ pub struct MVC {
     pub mc_inst: GlobalRef<'static>,
     pub mc_get_sound_mgr: usize,
     pub mc_player: usize,
+    pub mc_level_renderer: usize,
+    pub mc_hit_result: usize,
+    pub key_mapping: GlobalRef<'static>,
+    pub key_mapping_init: usize,
+    pub key_mapping_consume_click: usize,
+    pub model_res_loc: GlobalRef<'static>,
+    pub model_res_loc_standalone: usize,
+    pub model_res_loc_inventory: usize,
     pub sound_mgr_play: usize,
+    pub sound_mgr_stop: usize,
+    pub sound_inst: GlobalRef<'static>,
+    pub sound_inst_create_random: usize,
     pub simple_sound_inst: GlobalRef<'static>,
     pub simple_sound_inst_for_ui_holder: usize,
+    pub simple_sound_inst_init: usize,
+    pub sound_source_blocks: GlobalRef<'static>,
+    pub sound_attenuation_linear: GlobalRef<'static>,
     pub render_type_lightning: GlobalRef<'static>,
+    pub render_type_lines: GlobalRef<'static>,
+    pub level_renderer: GlobalRef<'static>,
+    pub level_renderer_render_line_box: usize,
     pub level_renderer_buffers: usize,
+    pub level_renderer_ticks: usize,
     pub render_buffers_buffer_source: usize,
     pub camera_pos: usize,
     pub buffer_uploader: GlobalRef<'static>,
@@ -1316,15 +2185,20 @@ impl MV {
         let sound_type = load(&cn.sound_type);
         let item = load(&cn.item);
         let item_stack = load(&cn.item_stack);
+        let item_props = load(&cn.item_props);
+        let custom_data = load(&cn.custom_data);
         let render_shape = load(&cn.render_shape);
         let resource_loc = load(&cn.resource_loc);
         let shapes = load(&cn.shapes);
         let nbt_compound = load(&cn.nbt_compound);
+        let nbt_list = load(&cn.nbt_list);
+        let nbt_string = load(&cn.nbt_string);
         let use_on_ctx = load(&cn.use_on_ctx);
         let dir = load(&cn.dir);
         let level = load(&cn.level);
         let friendly_byte_buf = load(&cn.friendly_byte_buf);
         let container_menu = load(&cn.container_menu);
+        let slot = load(&cn.slot);
         let chat_component = load(&cn.chat_component);
         let chat_fmt = load(&cn.chat_fmt);
         let interaction_result = load(&cn.interaction_result);
@@ -1335,9 +2209,13 @@ impl MV {
         let collision_ctx = load(&cn.collision_ctx);
         let collision_ctx_empty = mn.collision_ctx_empty.get_static_method_id(&collision_ctx).unwrap();
         let block_hit_result = load(&cn.block_hit_result);
+        let living_entity = load(&cn.living_entity);
         let registry = load(&cn.registry);
         let registries = load(&cn.registries);
         let custom_payload_type = load(&cn.custom_payload_type);
+        let fluids_water = load(&cn.fluids).static_field_2(&mn.fluids_water);
+        let integer = av.ldr.load_class(&av.jv, c"java.lang.Integer").unwrap().new_global_ref().unwrap();
+        let uuid = av.ldr.load_class(&av.jv, c"java.util.UUID").unwrap().new_global_ref().unwrap();
         MV {
             base_tile_block_init: mn.base_tile_block_init.get_method_id(&base_tile_block).unwrap(),
             block_default_state: mn.block_default_state.get_method_id(&block).unwrap(),
@@ -1345,6 +2223,7 @@ impl MV {
             block_beh_props_strength: mn.block_beh_props_strength.get_method_id(&block_beh_props).unwrap(),
             block_beh_props_dyn_shape: mn.block_beh_props_dyn_shape.get_method_id(&block_beh_props).unwrap(),
             block_beh_props_sound: mn.block_beh_props_sound.get_method_id(&block_beh_props).unwrap(),
+            block_beh_props_light_level: mn.block_beh_props_light_level.get_method_id(&block_beh_props).unwrap(),
             block_beh_props,
             block_item_init: mn.block_item_init.get_method_id(&block_item).unwrap(),
             block_item_place_block: mn.block_item_place_block.get_method_id(&block_item).unwrap(),
@@ -1363,6 +2242,14 @@ impl MV {
             block_pos,
             block_state_get_block: mn.block_state_get_block.get_method_id(&block_state).unwrap(),
             block_state_get_visual_shape: mn.block_state_get_visual_shape.get_method_id(&block_state).unwrap(),
+            block_state_can_be_replaced: mn.block_state_can_be_replaced.get_method_id(&block_state).unwrap(),
+            block_state_props_facing: load(&cn.block_state_props).static_field_2(&mn.block_state_props_facing),
+            prop: load(&cn.prop),
+            state_def_builder_add: mn.state_def_builder_add.get_method_id(&load(&cn.state_def_builder)).unwrap(),
+            state_holder_get_value: mn.state_holder_get_value.get_method_id(&load(&cn.state_holder)).unwrap(),
+            state_holder_set_value: mn.state_holder_set_value.get_method_id(&load(&cn.state_holder)).unwrap(),
+            rotation_rotate: mn.rotation_rotate.get_method_id(&load(&cn.rotation)).unwrap(),
+            mirror_get_rotation: mn.mirror_get_rotation.get_method_id(&load(&cn.mirror)).unwrap(),
             blocks_fire: load(&cn.blocks).static_field_2(&mn.blocks_fire),
             tile_type_init: mn.tile_type_init.get_method_id(&tile_type).unwrap(),
             tile_type,
@@ -1373,11 +2260,31 @@ impl MV {
             tile_pos: mn.tile_pos.get_field_id(&tile).unwrap(),
             tile_set_removed: mn.tile_set_removed.get_method_id(&tile).unwrap(),
             tile_set_changed: mn.tile_set_changed.get_method_id(&tile).unwrap(),
+            tile_set_block_state: mn.tile_set_block_state.get_method_id(&tile).unwrap(),
             tile,
             sound_type_metal: sound_type.static_field_2(&mn.sound_type_metal),
             item_get_desc_id: mn.item_get_desc_id.get_method_id(&item).unwrap(),
+            block_get_desc_id: mn.block_get_desc_id.get_method_id(&block).unwrap(),
+            item_init: mn.item_init.get_method_id(&item).unwrap(),
             item,
             item_stack_init: mn.item_stack_init.get_method_id(&item_stack).unwrap(),
+            item_stack_get_item: mn.item_stack_get_item.get_method_id(&item_stack).unwrap(),
+            item_stack_empty: item_stack.static_field_2(&mn.item_stack_empty),
+            item_stack_is_empty: mn.item_stack_is_empty.get_method_id(&item_stack).unwrap(),
+            item_stack_get_count: mn.item_stack_get_count.get_method_id(&item_stack).unwrap(),
+            item_stack_copy: mn.item_stack_copy.get_method_id(&item_stack).unwrap(),
+            item_stack_copy_with_count: mn.item_stack_copy_with_count.get_method_id(&item_stack).unwrap(),
+            item_stack_get_component: mn.item_stack_get_component.get_method_id(&item_stack).unwrap(),
+            item_stack_set_component: mn.item_stack_set_component.get_method_id(&item_stack).unwrap(),
+            data_components_custom_data: load(&cn.data_components).static_field_2(&mn.data_components_custom_data),
+            data_components_custom_name: load(&cn.data_components).static_field_2(&mn.data_components_custom_name),
+            custom_data_of: mn.custom_data_of.get_static_method_id(&custom_data).unwrap(),
+            custom_data_copy_tag: mn.custom_data_copy_tag.get_method_id(&custom_data).unwrap(),
+            custom_data,
+            list_add: av.ldr.load_class(&av.jv, c"java.util.List").unwrap().get_method_id(c"add", c"(Ljava/lang/Object;)Z").unwrap(),
+            item_props_init: mn.item_props_init.get_method_id(&item_props).unwrap(),
+            item_props_stacks_to: mn.item_props_stacks_to.get_method_id(&item_props).unwrap(),
+            item_props,
             item_stack,
             render_shape_tile: render_shape.static_field_2(&mn.render_shape_tile),
             resource_loc_init: mn.resource_loc_init.get_method_id(&resource_loc).unwrap(),
@@ -1387,24 +2294,66 @@ impl MV {
             nbt_compound_init: mn.nbt_compound_init.get_method_id(&nbt_compound).unwrap(),
             nbt_compound_put_byte_array: mn.nbt_compound_put_byte_array.get_method_id(&nbt_compound).unwrap(),
             nbt_compound_get_byte_array: mn.nbt_compound_get_byte_array.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_contains: mn.nbt_compound_contains.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_put: mn.nbt_compound_put.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_put_byte: mn.nbt_compound_put_byte.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_put_int: mn.nbt_compound_put_int.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_get_int: mn.nbt_compound_get_int.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_put_bool: mn.nbt_compound_put_bool.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_get_bool: mn.nbt_compound_get_bool.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_put_float: mn.nbt_compound_put_float.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_get_float: mn.nbt_compound_get_float.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_put_long: mn.nbt_compound_put_long.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_get_long: mn.nbt_compound_get_long.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_get_list: mn.nbt_compound_get_list.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_get_compound: mn.nbt_compound_get_compound.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_put_string: mn.nbt_compound_put_string.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_get_string: mn.nbt_compound_get_string.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_put_uuid: mn.nbt_compound_put_uuid.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_has_uuid: mn.nbt_compound_has_uuid.get_method_id(&nbt_compound).unwrap(),
+            nbt_compound_get_uuid: mn.nbt_compound_get_uuid.get_method_id(&nbt_compound).unwrap(),
             nbt_compound,
+            nbt_list_init: mn.nbt_list_init.get_method_id(&nbt_list).unwrap(),
+            nbt_list_add: mn.nbt_list_add.get_method_id(&nbt_list).unwrap(),
+            nbt_list_size: mn.nbt_list_size.get_method_id(&nbt_list).unwrap(),
+            nbt_list_get_string: mn.nbt_list_get_string.get_method_id(&nbt_list).unwrap(),
+            nbt_list,
+            nbt_string_value_of: mn.nbt_string_value_of.get_static_method_id(&nbt_string).unwrap(),
+            nbt_string,
             use_on_ctx_get_level: mn.use_on_ctx_get_level.get_method_id(&use_on_ctx).unwrap(),
             use_on_ctx_get_clicked_pos: mn.use_on_ctx_get_clicked_pos.get_method_id(&use_on_ctx).unwrap(),
             use_on_ctx_get_clicked_face: mn.use_on_ctx_get_clicked_face.get_method_id(&use_on_ctx).unwrap(),
+            use_on_ctx_get_item: mn.use_on_ctx_get_item.get_method_id(&use_on_ctx).unwrap(),
+            use_on_ctx_get_player: mn.use_on_ctx_get_player.get_method_id(&use_on_ctx).unwrap(),
             dir_3d_data: mn.dir_3d_data.get_field_id(&dir).unwrap(),
             dir_by_3d_data: dir.static_field_2(&mn.dir_by_3d_data),
             level_set_block_and_update: mn.level_set_block_and_update.get_method_id(&level).unwrap(),
+            level_set_block: mn.level_set_block.get_method_id(&level).unwrap(),
+            level_remove_block: mn.level_remove_block.get_method_id(&level).unwrap(),
             level_update_neighbors_for_out_signal: mn.level_update_neighbors_for_out_signal.get_method_id(&level).unwrap(),
             level_is_client: mn.level_is_client.get_field_id(&level).unwrap(),
             level_get_chunk_source: mn.level_get_chunk_source.get_method_id(&level).unwrap(),
             level_is_outside_build_height: mn.level_is_outside_build_height.get_method_id(&level).unwrap(),
+            level_get_game_time: mn.level_get_game_time.get_method_id(&level).unwrap(),
             friendly_byte_buf_read_byte_array: mn.friendly_byte_buf_read_byte_array.get_method_id(&friendly_byte_buf).unwrap(),
             friendly_byte_buf_write_byte_array: mn.friendly_byte_buf_write_byte_array.get_method_id(&friendly_byte_buf).unwrap(),
             container_menu_init: mn.container_menu_init.get_method_id(&container_menu).unwrap(),
             container_menu_id: mn.container_menu_id.get_field_id(&container_menu).unwrap(),
+            container_menu_add_slot: mn.container_menu_add_slot.get_method_id(&container_menu).unwrap(),
+            container_menu_get_slot: mn.container_menu_get_slot.get_method_id(&container_menu).unwrap(),
+            container_menu_move_item_stack_to: mn.container_menu_move_item_stack_to.get_method_id(&container_menu).unwrap(),
+            slot_init: mn.slot_init.get_method_id(&slot).unwrap(),
+            slot_has_item: mn.slot_has_item.get_method_id(&slot).unwrap(),
+            slot_get_item: mn.slot_get_item.get_method_id(&slot).unwrap(),
+            slot_set_by_player: mn.slot_set_by_player.get_method_id(&slot).unwrap(),
+            slot_on_take: mn.slot_on_take.get_method_id(&slot).unwrap(),
+            slot,
             chat_component_translatable: mn.chat_component_translatable.get_static_method_id(&chat_component).unwrap(),
+            chat_component_translatable_args: mn.chat_component_translatable_args.get_static_method_id(&chat_component).unwrap(),
             chat_component_literal: mn.chat_component_literal.get_static_method_id(&chat_component).unwrap(),
+            chat_component_get_string: mn.chat_component_get_string.get_method_id(&chat_component).unwrap(),
             chat_component_to_formatted: mn.chat_component_to_formatted.get_method_id(&chat_component).unwrap(),
+            chat_mutable_component_with_style: mn.chat_mutable_component_with_style.get_method_id(&load(&cn.chat_mutable_component)).unwrap(),
             chat_component,
             chat_fmt_from_code: mn.chat_fmt_from_code.get_static_method_id(&chat_fmt).unwrap(),
             chat_fmt_color: mn.chat_fmt_color.get_field_id(&chat_fmt).unwrap(),
@@ -1412,8 +2361,14 @@ impl MV {
             interaction_result_pass: interaction_result.static_field_1(c"PASS", &cn.interaction_result.sig),
             interaction_result_success: interaction_result.static_field_1(c"SUCCESS", &cn.interaction_result.sig),
             interaction_result_consume: interaction_result.static_field_1(c"CONSUME", &cn.interaction_result.sig),
+            item_interaction_result_success: load(&cn.item_interaction_result).static_field_1(c"SUCCESS", &cn.item_interaction_result.sig),
+            item_interaction_result_pass: load(&cn.item_interaction_result)
+                .static_field_1(c"PASS_TO_DEFAULT_BLOCK_INTERACTION", &cn.item_interaction_result.sig),
             server_player,
             inventory_player: mn.inventory_player.get_field_id(&load(&cn.inventory)).unwrap(),
+            inventory_place_back: mn.inventory_place_back.get_method_id(&load(&cn.inventory)).unwrap(),
+            player_get_inventory: mn.player_get_inventory.get_method_id(&player).unwrap(),
+            entity_is_shift_key_down: mn.entity_is_shift_key_down.get_method_id(&load(&cn.entity)).unwrap(),
             entity_level: mn.entity_level.get_method_id(&load(&cn.entity)).unwrap(),
             entity_y_rot: mn.entity_y_rot.get_field_id(&load(&cn.entity)).unwrap(),
             container_still_valid: mn.container_still_valid.get_static_method_id(&container).unwrap(),
@@ -1421,6 +2376,30 @@ impl MV {
             player_profile: mn.player_profile.get_field_id(&player).unwrap(),
             player_container_menu: mn.player_container_menu.get_field_id(&player).unwrap(),
             game_profile_get_name: mn.game_profile_get_name.get_method_id(&load(&cn.game_profile)).unwrap(),
+            entity_get_uuid: mn.entity_get_uuid.get_method_id(&load(&cn.entity)).unwrap(),
+            entity_get_team: mn.entity_get_team.get_method_id(&load(&cn.entity)).unwrap(),
+            entity_get_server: mn.entity_get_server.get_method_id(&load(&cn.entity)).unwrap(),
+            mc_server_get_profile_cache: mn.mc_server_get_profile_cache.get_method_id(&load(&cn.mc_server)).unwrap(),
+            profile_cache_get: mn.profile_cache_get.get_method_id(&load(&cn.profile_cache)).unwrap(),
+            player_team_get_players: mn.player_team_get_players.get_method_id(&load(&cn.player_team)).unwrap(),
+            player_has_permissions: mn.player_has_permissions.get_method_id(&player).unwrap(),
+            player_display_client_message: mn.player_display_client_message.get_method_id(&player).unwrap(),
+            uuid_init: uuid.get_method_id(c"<init>", c"(JJ)V").unwrap(),
+            uuid_msb: uuid.get_method_id(c"getMostSignificantBits", c"()J").unwrap(),
+            uuid_lsb: uuid.get_method_id(c"getLeastSignificantBits", c"()J").unwrap(),
+            uuid,
+            collection_contains: av
+                .ldr
+                .load_class(&av.jv, c"java.util.Collection")
+                .unwrap()
+                .get_method_id(c"contains", c"(Ljava/lang/Object;)Z")
+                .unwrap(),
+            optional_or_else: av
+                .ldr
+                .load_class(&av.jv, c"java.util.Optional")
+                .unwrap()
+                .get_method_id(c"orElse", c"(Ljava/lang/Object;)Ljava/lang/Object;")
+                .unwrap(),
             sound_evts_ui_btn_click: load(&cn.sound_evts).static_field_2(&mn.sound_evts_ui_btn_click),
             server_chunk_cache_block_changed: mn.server_chunk_cache_block_changed.get_method_id(&load(&cn.server_chunk_cache)).unwrap(),
             chunk_pos_x: mn.chunk_pos_x.get_field_id(&chunk_pos).unwrap(),
@@ -1430,6 +2409,10 @@ impl MV {
             block_hit_result_pos: mn.block_hit_result_pos.get_field_id(&block_hit_result).unwrap(),
             block_hit_result_miss: mn.block_hit_result_miss.get_field_id(&block_hit_result).unwrap(),
             block_hit_result_dir: mn.block_hit_result_dir.get_field_id(&block_hit_result).unwrap(),
+            block_hit_result_block_pos: mn.block_hit_result_block_pos.get_field_id(&block_hit_result).unwrap(),
+            block_hit_result,
+            living_entity_main_hand_item: mn.living_entity_main_hand_item.get_method_id(&living_entity).unwrap(),
+            living_entity_offhand_item: mn.living_entity_offhand_item.get_method_id(&living_entity).unwrap(),
             chunk_access_pos: mn.chunk_access_pos.get_field_id(&load(&cn.chunk_access)).unwrap(),
             level_chunk: load(&cn.level_chunk),
             chunk_source_get_chunk_now: mn.chunk_source_get_chunk_now.get_method_id(&load(&cn.chunk_source)).unwrap(),
@@ -1438,12 +2421,34 @@ impl MV {
             reg_key_block: registries.static_field_2(&mn.reg_key_block),
             reg_key_tile_type: registries.static_field_2(&mn.reg_key_tile_type),
             reg_key_menu: registries.static_field_2(&mn.reg_key_menu),
+            reg_key_item: registries.static_field_2(&mn.reg_key_item),
+            loot_builder_get_optional: mn.loot_builder_get_optional.get_method_id(&load(&cn.loot_builder)).unwrap(),
+            loot_ctx_params_tile: load(&cn.loot_ctx_params).static_field_2(&mn.loot_ctx_params_tile),
             custom_payload_type_init: mn.custom_payload_type_init.get_method_id(&custom_payload_type).unwrap(),
             custom_payload_type,
+            block_beh_get_fluid_state: mn.block_beh_get_fluid_state.get_method_id(&block).unwrap(),
+            block_beh_update_shape: mn.block_beh_update_shape.get_method_id(&block).unwrap(),
+            block,
+            block_getter_get_fluid_state: mn.block_getter_get_fluid_state.get_method_id(&block_getter).unwrap(),
+            block_state_props_waterlogged: load(&cn.block_state_props).static_field_2(&mn.block_state_props_waterlogged),
+            block_state_props_level: load(&cn.block_state_props).static_field_2(&mn.block_state_props_level),
+            fluids_water_source: fluids_water
+                .call_object_method(mn.flowing_fluid_get_source.get_method_id(&load(&cn.flowing_fluid)).unwrap(), &[0])
+                .unwrap()
+                .unwrap()
+                .new_global_ref()
+                .unwrap(),
+            fluids_water,
+            fluid_get_tick_delay: mn.fluid_get_tick_delay.get_method_id(&load(&cn.fluid)).unwrap(),
+            fluid_state_get_type: mn.fluid_state_get_type.get_method_id(&load(&cn.fluid_state)).unwrap(),
+            level_access_schedule_fluid_tick: mn.level_access_schedule_fluid_tick.get_method_id(&load(&cn.level_access)).unwrap(),
+            integer_value_of: integer.get_static_method_id(c"valueOf", c"(I)Ljava/lang/Integer;").unwrap(),
+            integer,
             client: is_client.then(|| {
                 let pose = load(&cn.pose);
                 let pose_stack = load(&cn.pose_stack);
                 let matrix4fc = load(&cn.matrix4fc);
+                let matrix4f = load(&cn.matrix4f);
                 let atlas = load(&cn.atlas);
                 let sprite = load(&cn.sprite);
                 let vertex_consumer = load(&cn.vertex_consumer);
@@ -1451,6 +2456,7 @@ impl MV {
                 let screen = load(&cn.screen);
                 let container_screen = load(&cn.container_screen);
                 let gui_graphics = load(&cn.gui_graphics);
+                let narrated_element_type = load(&cn.narrated_element_type);
                 let render_sys = load(&cn.render_sys);
                 let tesselator = load(&cn.tesselator);
                 let buffer_builder = load(&cn.buffer_builder);
@@ -1461,12 +2467,20 @@ impl MV {
                 let mc_inst = mc.call_static_object_method(mn.mc_get_inst.get_static_method_id(&mc).unwrap(), &[]).unwrap().unwrap();
                 let window_inst = mc_inst.call_object_method(mn.mc_get_window.get_method_id(&mc).unwrap(), &[]).unwrap().unwrap();
                 let window = window_inst.get_object_class();
+                let sound_mgr = load(&cn.sound_mgr);
+                let sound_inst = load(&cn.sound_inst);
                 let simple_sound_inst = load(&cn.simple_sound_inst);
                 let buffer_uploader = load(&cn.buffer_uploader);
+                let level_renderer = load(&cn.level_renderer);
+                let key_mapping = load(&cn.key_mapping);
+                let model_res_loc = load(&cn.model_res_loc);
                 MVC {
                     pose_pose: mn.pose_pose.get_field_id(&pose).unwrap(),
                     pose_stack_last: mn.pose_stack_last.get_method_id(&pose_stack).unwrap(),
                     matrix4fc_read: mn.matrix4fc_read.get_method_id(&matrix4fc).unwrap(),
+                    matrix4f_init: mn.matrix4f_init.get_method_id(&matrix4f).unwrap(),
+                    matrix4f_write: mn.matrix4f_write.get_method_id(&matrix4f).unwrap(),
+                    matrix4f,
                     atlas_loc: mn.atlas_loc.get_method_id(&atlas).unwrap(),
                     atlas_loc_blocks: atlas.static_field_2(&mn.atlas_loc_blocks),
                     atlas_get_sprite: mn.atlas_get_sprite.get_method_id(&atlas).unwrap(),
@@ -1486,6 +2500,13 @@ impl MV {
                     screen_width: mn.screen_width.get_field_id(&screen).unwrap(),
                     screen_height: mn.screen_height.get_field_id(&screen).unwrap(),
                     screen_render_background: mn.screen_render_background.get_method_id(&screen).unwrap(),
+                    screen_has_shift_down: mn.screen_has_shift_down.get_static_method_id(&screen).unwrap(),
+                    screen_has_control_down: mn.screen_has_control_down.get_static_method_id(&screen).unwrap(),
+                    screen_update_narration: mn.screen_update_narration.get_method_id(&screen).unwrap(),
+                    narration_output_add: mn.narration_output_add.get_method_id(&load(&cn.narration_element_output)).unwrap(),
+                    narrated_element_type_position: narrated_element_type.static_field_2(&mn.narrated_element_type_position),
+                    narrated_element_type_hint: narrated_element_type.static_field_2(&mn.narrated_element_type_hint),
+                    screen,
                     container_screen_init: mn.container_screen_init.get_method_id(&container_screen).unwrap(),
                     container_screen_img_width: mn.container_screen_img_width.get_field_id(&container_screen).unwrap(),
                     container_screen_img_height: mn.container_screen_img_height.get_field_id(&container_screen).unwrap(),
@@ -1497,9 +2518,13 @@ impl MV {
                     container_screen_mouse_clicked: mn.container_screen_mouse_clicked.get_method_id(&container_screen).unwrap(),
                     container_screen_mouse_dragged: mn.container_screen_mouse_dragged.get_method_id(&container_screen).unwrap(),
                     container_screen_mouse_released: mn.container_screen_mouse_released.get_method_id(&container_screen).unwrap(),
+                    container_screen_mouse_scrolled: mn.container_screen_mouse_scrolled.get_method_id(&container_screen).unwrap(),
+                    container_screen_key_pressed: mn.container_screen_key_pressed.get_method_id(&container_screen).unwrap(),
+                    container_screen_char_typed: mn.container_screen_char_typed.get_method_id(&container_screen).unwrap(),
                     container_screen,
                     gui_graphics_draw_formatted: mn.gui_graphics_draw_formatted.get_method_id(&gui_graphics).unwrap(),
                     gui_graphics_pose: mn.gui_graphics_pose.get_field_id(&gui_graphics).unwrap(),
+                    gui_graphics_buffer_source: mn.gui_graphics_buffer_source.get_method_id(&gui_graphics).unwrap(),
                     render_sys_set_shader: mn.render_sys_set_shader.get_static_method_id(&render_sys).unwrap(),
                     render_sys_enable_blend: mn.render_sys_enable_blend.get_static_method_id(&render_sys).unwrap(),
                     render_sys_enable_cull: mn.render_sys_enable_cull.get_static_method_id(&render_sys).unwrap(),
@@ -1519,13 +2544,31 @@ impl MV {
                     font_width: mn.font_width.get_method_id(&load(&cn.font)).unwrap(),
                     mc_get_sound_mgr: mn.mc_get_sound_mgr.get_method_id(&mc).unwrap(),
                     mc_player: mn.mc_player.get_field_id(&mc).unwrap(),
+                    mc_level_renderer: mn.mc_level_renderer.get_field_id(&mc).unwrap(),
+                    mc_hit_result: mn.mc_hit_result.get_field_id(&mc).unwrap(),
+                    key_mapping_init: mn.key_mapping_init.get_method_id(&key_mapping).unwrap(),
+                    key_mapping_consume_click: mn.key_mapping_consume_click.get_method_id(&key_mapping).unwrap(),
+                    key_mapping,
+                    model_res_loc_standalone: mn.model_res_loc_standalone.get_static_method_id(&model_res_loc).unwrap(),
+                    model_res_loc_inventory: mn.model_res_loc_inventory.get_static_method_id(&model_res_loc).unwrap(),
+                    model_res_loc,
                     mc_inst: mc_inst.new_global_ref().unwrap(),
                     mc,
-                    sound_mgr_play: mn.sound_mgr_play.get_method_id(&load(&cn.sound_mgr)).unwrap(),
+                    sound_mgr_play: mn.sound_mgr_play.get_method_id(&sound_mgr).unwrap(),
+                    sound_mgr_stop: mn.sound_mgr_stop.get_method_id(&sound_mgr).unwrap(),
+                    sound_inst_create_random: mn.sound_inst_create_random.get_static_method_id(&sound_inst).unwrap(),
+                    sound_inst,
                     simple_sound_inst_for_ui_holder: mn.simple_sound_inst_for_ui_holder.get_static_method_id(&simple_sound_inst).unwrap(),
+                    simple_sound_inst_init: mn.simple_sound_inst_init.get_method_id(&simple_sound_inst).unwrap(),
                     simple_sound_inst,
+                    sound_source_blocks: load(&cn.sound_source).static_field_2(&mn.sound_source_blocks),
+                    sound_attenuation_linear: load(&cn.sound_attenuation).static_field_2(&mn.sound_attenuation_linear),
                     render_type_lightning: load(&cn.render_type).static_field_2(&mn.render_type_lightning),
-                    level_renderer_buffers: mn.level_renderer_buffers.get_field_id(&load(&cn.level_renderer)).unwrap(),
+                    render_type_lines: load(&cn.render_type).static_field_2(&mn.render_type_lines),
+                    level_renderer_render_line_box: mn.level_renderer_render_line_box.get_static_method_id(&level_renderer).unwrap(),
+                    level_renderer_buffers: mn.level_renderer_buffers.get_field_id(&level_renderer).unwrap(),
+                    level_renderer_ticks: mn.level_renderer_ticks.get_field_id(&level_renderer).unwrap(),
+                    level_renderer,
                     render_buffers_buffer_source: mn.render_buffers_buffer_source.get_field_id(&load(&cn.render_buffers)).unwrap(),
                     camera_pos: mn.camera_pos.get_field_id(&load(&cn.camera)).unwrap(),
                     buffer_uploader_draw: mn.buffer_uploader_draw.get_static_method_id(&buffer_uploader).unwrap(),
@@ -1550,6 +2593,8 @@ pub struct GregCN<T> {
     pub energy_container: T,
     pub pipe_block: T,
     pub pipe_node: T,
+    pub tool_helper: T,
+    pub tool_type: T,
 }
 
 impl GregCN<Arc<CSig>> {
@@ -1566,6 +2611,8 @@ impl GregCN<Arc<CSig>> {
             energy_container: b"com.gregtechceu.gtceu.api.capability.IEnergyContainer",
             pipe_block: b"com.gregtechceu.gtceu.api.block.PipeBlock",
             pipe_node: b"com.gregtechceu.gtceu.api.pipenet.IPipeNode",
+            tool_helper: b"com.gregtechceu.gtceu.api.item.tool.ToolHelper",
+            tool_type: b"com.gregtechceu.gtceu.api.item.tool.GTToolType",
         };
         names.fmap(|x| Arc::new(CSig::new(x)))
     }
@@ -1584,6 +2631,9 @@ pub struct GregMN {
     pub pipe_block_get_node: MSig,
     pub pipe_block_can_connect: MSig,
     pub pipe_node_set_conn: MSig,
+    pub tool_helper_is: MSig,
+    pub tool_helper_play_sound: MSig,
+    pub tool_helper_damage: MSig,
 }
 
 impl GregMN {
@@ -1617,6 +2667,21 @@ impl GregMN {
                 sig: msig([gcn.pipe_node.sig.to_bytes(), cn.dir.sig.to_bytes(), cn.tile.sig.to_bytes()], b"Z"),
             },
             pipe_node_set_conn: MSig { owner: gcn.pipe_node.clone(), name: cs("setConnection"), sig: msig([cn.dir.sig.to_bytes(), b"ZZ"], b"V") },
+            tool_helper_is: MSig {
+                owner: gcn.tool_helper.clone(),
+                name: cs("is"),
+                sig: msig([cn.item_stack.sig.to_bytes(), gcn.tool_type.sig.to_bytes()], b"Z"),
+            },
+            tool_helper_play_sound: MSig {
+                owner: gcn.tool_helper.clone(),
+                name: cs("playToolSound"),
+                sig: msig([gcn.tool_type.sig.to_bytes(), cn.server_player.sig.to_bytes()], b"V"),
+            },
+            tool_helper_damage: MSig {
+                owner: gcn.tool_helper.clone(),
+                name: cs("damageItem"),
+                sig: msig([cn.item_stack.sig.to_bytes(), cn.living_entity.sig.to_bytes(), b"I"], b"V"),
+            },
         }
     }
 }
@@ -1633,6 +2698,12 @@ pub struct GregMV {
     pub pipe_node_set_conn: usize,
     pub can_input_eu_from_side: usize,
     pub accept_eu: usize,
+    pub tool_helper: GlobalRef<'static>,
+    pub tool_helper_is: usize,
+    pub tool_helper_play_sound: usize,
+    pub tool_helper_damage: usize,
+    pub tool_type_wrench: GlobalRef<'static>,
+    pub tool_type_screwdriver: GlobalRef<'static>,
 }
 
 impl GregMV {
@@ -1642,6 +2713,8 @@ impl GregMV {
         let values = load(&gcn.values);
         let pipe_block = load(&gcn.pipe_block);
         let energy_container = load(&gcn.energy_container);
+        let tool_helper = load(&gcn.tool_helper);
+        let tool_type = load(&gcn.tool_type);
         Self {
             tier_names: values.static_field_1(c"VN", c"[Ljava/lang/String;"),
             tier_volts: values.static_field_1(c"V", c"[J"),
@@ -1654,6 +2727,12 @@ impl GregMV {
             pipe_node_set_conn: gmn.pipe_node_set_conn.get_method_id(&load(&gcn.pipe_node)).unwrap(),
             can_input_eu_from_side: gmn.can_input_eu_from_side.get_method_id(&energy_container).unwrap(),
             accept_eu: gmn.accept_eu.get_method_id(&energy_container).unwrap(),
+            tool_helper_is: gmn.tool_helper_is.get_static_method_id(&tool_helper).unwrap(),
+            tool_helper_play_sound: gmn.tool_helper_play_sound.get_static_method_id(&tool_helper).unwrap(),
+            tool_helper_damage: gmn.tool_helper_damage.get_static_method_id(&tool_helper).unwrap(),
+            tool_helper,
+            tool_type_wrench: tool_type.static_field_1(c"WRENCH", &gcn.tool_type.sig),
+            tool_type_screwdriver: tool_type.static_field_1(c"SCREWDRIVER", &gcn.tool_type.sig),
         }
     }
 }
@@ -7,6 +7,7 @@ pub mod nbt;
 pub mod network;
 pub mod tessellator;
 pub mod tile;
+pub mod widgets;
 
 use self::cleaner::Cleanable;
 use crate::{
@@ -17,7 +18,7 @@ use crate::{
     objs,
     registry::MOD_ID,
 };
-use alloc::{ffi::CString, format, sync::Arc, vec::Vec};
+use alloc::{ffi::CString, format, string::String, sync::Arc, vec::Vec};
 use anyhow::{anyhow, ensure, Result};
 use core::{
     ffi::CStr,
@@ -33,6 +34,23 @@ pub trait UtilExt<'a>: JRef<'a> {
     fn static_field_1(&self, name: &CStr, sig: &CStr) -> GlobalRef<'a> {
         self.get_static_object_field(self.get_static_field_id(name, sig).unwrap()).unwrap().new_global_ref().unwrap()
     }
+
+    fn read_uuid(&self) -> u128 {
+        let mv = &objs().mv;
+        let msb = self.call_long_method(mv.uuid_msb, &[]).unwrap() as u64;
+        let lsb = self.call_long_method(mv.uuid_lsb, &[]).unwrap() as u64;
+        (msb as u128) << 64 | lsb as u128
+    }
+}
+
+pub fn write_uuid(jni: &JNI, id: u128) -> LocalRef {
+    let mv = &objs().mv;
+    mv.uuid.with_jni(jni).new_object(mv.uuid_init, &[(id >> 64) as u64 as _, id as u64 as _]).unwrap()
+}
+
+// Hyphenated, as Java's UUID.toString writes it.
+pub fn uuid_str(id: u128) -> String {
+    format!("{:08x}-{:04x}-{:04x}-{:04x}-{:012x}", id >> 96, id >> 80 & 0xffff, id >> 64 & 0xffff, id >> 48 & 0xffff, id & 0xffff_ffff_ffff)
 }
 
 pub fn strict_deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
@@ -1,9 +1,16 @@
-use crate::{jvm::*, objs};
+use super::{write_uuid, UtilExt};
+use crate::{jvm::*, mapping_base::*, objs};
+use alloc::vec::Vec;
 use core::ffi::CStr;
 
 pub const KEY_SYNC: &CStr = c"c";
+// Postcard blob that save data was written as before it got typed tags. Still read for old saves.
 pub const KEY_SAVE: &CStr = c"s";
 
+pub const TAG_STRING: i32 = 8;
+pub const TAG_COMPOUND: i32 = 10;
+pub const TAG_ANY_NUMERIC: i32 = 99;
+
 impl<'a, T: JRef<'a>> NBTExt<'a> for T {}
 pub trait NBTExt<'a>: JRef<'a> {
     fn compound_put_byte_array(&self, key: &CStr, data: &[u8]) {
@@ -15,6 +22,103 @@ pub trait NBTExt<'a>: JRef<'a> {
     fn compound_get_byte_array(&self, key: &CStr) -> LocalRef<'a> {
         self.call_object_method(objs().mv.nbt_compound_get_byte_array, &[self.jni().new_utf(key).unwrap().raw]).unwrap().unwrap()
     }
+
+    fn compound_contains(&self, key: &CStr, tag_type: i32) -> bool {
+        self.call_bool_method(objs().mv.nbt_compound_contains, &[self.jni().new_utf(key).unwrap().raw, tag_type as _]).unwrap()
+    }
+
+    fn compound_put_byte(&self, key: &CStr, x: i8) {
+        self.call_void_method(objs().mv.nbt_compound_put_byte, &[self.jni().new_utf(key).unwrap().raw, x as _]).unwrap()
+    }
+
+    fn compound_put_int(&self, key: &CStr, x: i32) {
+        self.call_void_method(objs().mv.nbt_compound_put_int, &[self.jni().new_utf(key).unwrap().raw, x as _]).unwrap()
+    }
+
+    fn compound_put_bool(&self, key: &CStr, x: bool) {
+        self.call_void_method(objs().mv.nbt_compound_put_bool, &[self.jni().new_utf(key).unwrap().raw, x as _]).unwrap()
+    }
+
+    fn compound_put_float(&self, key: &CStr, x: f32) {
+        self.call_void_method(objs().mv.nbt_compound_put_float, &[self.jni().new_utf(key).unwrap().raw, f_raw(x)]).unwrap()
+    }
+
+    fn compound_put_long(&self, key: &CStr, x: i64) {
+        self.call_void_method(objs().mv.nbt_compound_put_long, &[self.jni().new_utf(key).unwrap().raw, x as _]).unwrap()
+    }
+
+    fn compound_put_string(&self, key: &CStr, x: &str) {
+        let x = self.jni().new_utf(&cs(x)).unwrap();
+        self.call_void_method(objs().mv.nbt_compound_put_string, &[self.jni().new_utf(key).unwrap().raw, x.raw]).unwrap()
+    }
+
+    fn compound_put_uuid(&self, key: &CStr, id: u128) {
+        let id = write_uuid(self.jni(), id);
+        self.call_void_method(objs().mv.nbt_compound_put_uuid, &[self.jni().new_utf(key).unwrap().raw, id.raw]).unwrap()
+    }
+
+    fn compound_put_compound(&self, key: &CStr, x: &impl JRef<'a>) {
+        self.call_object_method(objs().mv.nbt_compound_put, &[self.jni().new_utf(key).unwrap().raw, x.raw()]).unwrap();
+    }
+
+    fn compound_put_string_list<'b>(&self, key: &CStr, items: impl IntoIterator<Item = &'b str>) {
+        let mv = &objs().mv;
+        let list = mv.nbt_list.with_jni(self.jni()).new_object(mv.nbt_list_init, &[]).unwrap();
+        for item in items {
+            let item =
+                mv.nbt_string.with_jni(self.jni()).call_static_object_method(mv.nbt_string_value_of, &[self.jni().new_utf(&cs(item)).unwrap().raw]);
+            list.call_bool_method(mv.nbt_list_add, &[item.unwrap().unwrap().raw]).unwrap();
+        }
+        self.call_object_method(mv.nbt_compound_put, &[self.jni().new_utf(key).unwrap().raw, list.raw]).unwrap();
+    }
+
+    // The getters below return None if the tag is missing or of the wrong type, rather than a default.
+
+    fn compound_get_int(&self, key: &CStr) -> Option<i32> {
+        let true = self.compound_contains(key, TAG_ANY_NUMERIC) else { return None };
+        Some(self.call_int_method(objs().mv.nbt_compound_get_int, &[self.jni().new_utf(key).unwrap().raw]).unwrap())
+    }
+
+    fn compound_get_bool(&self, key: &CStr) -> Option<bool> {
+        let true = self.compound_contains(key, TAG_ANY_NUMERIC) else { return None };
+        Some(self.call_bool_method(objs().mv.nbt_compound_get_bool, &[self.jni().new_utf(key).unwrap().raw]).unwrap())
+    }
+
+    fn compound_get_float(&self, key: &CStr) -> Option<f32> {
+        let true = self.compound_contains(key, TAG_ANY_NUMERIC) else { return None };
+        Some(self.call_float_method(objs().mv.nbt_compound_get_float, &[self.jni().new_utf(key).unwrap().raw]).unwrap())
+    }
+
+    fn compound_get_long(&self, key: &CStr) -> Option<i64> {
+        let true = self.compound_contains(key, TAG_ANY_NUMERIC) else { return None };
+        Some(self.call_long_method(objs().mv.nbt_compound_get_long, &[self.jni().new_utf(key).unwrap().raw]).unwrap())
+    }
+
+    fn compound_get_string(&self, key: &CStr) -> Option<Vec<u8>> {
+        let true = self.compound_contains(key, TAG_STRING) else { return None };
+        let x = self.call_object_method(objs().mv.nbt_compound_get_string, &[self.jni().new_utf(key).unwrap().raw]).unwrap().unwrap();
+        Some(x.utf_chars().unwrap().to_vec())
+    }
+
+    fn compound_get_uuid(&self, key: &CStr) -> Option<u128> {
+        let key = self.jni().new_utf(key).unwrap();
+        let true = self.call_bool_method(objs().mv.nbt_compound_has_uuid, &[key.raw]).unwrap() else { return None };
+        Some(self.call_object_method(objs().mv.nbt_compound_get_uuid, &[key.raw]).unwrap().unwrap().read_uuid())
+    }
+
+    fn compound_get_compound(&self, key: &CStr) -> Option<LocalRef<'a>> {
+        let true = self.compound_contains(key, TAG_COMPOUND) else { return None };
+        self.call_object_method(objs().mv.nbt_compound_get_compound, &[self.jni().new_utf(key).unwrap().raw]).unwrap()
+    }
+
+    // An empty list is indistinguishable from a missing one, since NBT lists with no elements carry no type.
+    fn compound_get_string_list(&self, key: &CStr) -> Vec<Vec<u8>> {
+        let mv = &objs().mv;
+        let list = self.call_object_method(mv.nbt_compound_get_list, &[self.jni().new_utf(key).unwrap().raw, TAG_STRING as _]).unwrap().unwrap();
+        let len = list.call_int_method(mv.nbt_list_size, &[]).unwrap();
+        let items = (0..len).map(|i| list.call_object_method(mv.nbt_list_get_string, &[i as _]).unwrap().unwrap().utf_chars().unwrap().to_vec());
+        items.collect()
+    }
 }
 
 pub fn new_compound(jni: &JNI) -> LocalRef {
@@ -1,5 +1,6 @@
 use super::{
     cleaner::Cleanable,
+    gui::GUIExt,
     mapping::{ForgeCN, ForgeMN, CN, MN, MV},
     ClassBuilder, ClassNamer, ThinWrapper,
 };
@@ -9,7 +10,7 @@ use crate::{
     jvm::*,
     mapping_base::{cs, CSig, MSig},
     objs,
-    packets::{handle_c2s, handle_s2c},
+    packets::{handle_c2s, handle_s2c, S2C},
     registry::MOD_ID,
 };
 use alloc::{format, sync::Arc, vec::Vec};
@@ -75,6 +76,8 @@ impl NetworkDefs {
         let data = self.payload.new_obj(jni, Payload(postcard::to_allocvec(data).unwrap()).into());
         fmv.pkt_distributor.with_jni(jni).call_static_void_method(fmv.send_s2c, &[player.raw(), data.raw, self.empty_payload_array.raw]).unwrap()
     }
+
+    pub fn send_menu_data<'a>(&self, player: &impl JRef<'a>, menu_id: i32, data: Vec<u8>) { self.send_s2c(player, &S2C::MenuData { menu_id, data }) }
 }
 
 #[dyn_abi]
@@ -104,20 +107,22 @@ fn decode(jni: &JNI, _: usize, buf: usize) -> usize {
 
 #[dyn_abi]
 fn handle_payload(jni: &'static JNI, _this: usize, payload: usize, ctx: usize) {
-    let GlobalObjs { mtx, fmv, mv, net_defs, .. } = objs();
+    let GlobalObjs { mtx, mv, fmv, net_defs, .. } = objs();
     let lk = mtx.lock(jni).unwrap();
     let data = &*net_defs.payload.read(&*lk, BorrowedRef::new(jni, &payload)).0;
     let ctx = BorrowedRef::new(jni, &ctx);
     if ctx.call_object_method(fmv.payload_ctx_flow, &[]).unwrap().unwrap().call_bool_method(fmv.pkt_flow_is_s2c, &[]).unwrap() {
-        if let Err(e) = handle_s2c(&*lk, data) {
+        if let Err(e) = handle_s2c(&*lk, jni, data) {
             warn(jni, &cs(format!("Failed to handle packet from server: {e:?}")))
         }
     } else {
         let player = ctx.call_object_method(fmv.payload_ctx_player, &[]).unwrap().unwrap();
-        if let Err(e) = handle_c2s(&lk, data, player.borrow()) {
-            let profile = player.get_object_field(mv.player_profile).unwrap();
-            let name = profile.call_object_method(mv.game_profile_get_name, &[]).unwrap().unwrap();
-            warn(jni, &cs(format!("Failed to handle packet from player {}: {e:?}", BStr::new(&*name.utf_chars().unwrap()))))
-        }
+        let Err(e) = handle_c2s(&lk, data, player.borrow()) else { return };
+        drop(lk);
+        let profile = player.get_object_field(mv.player_profile).unwrap();
+        let name = profile.call_object_method(mv.game_profile_get_name, &[]).unwrap().unwrap();
+        warn(jni, &cs(format!("Disconnecting player {} for a malformed packet: {e:?}", BStr::new(&*name.utf_chars().unwrap()))));
+        let msg = jni.new_utf(c"greg_emitters.disconnect.bad_packet").unwrap().translatable();
+        ctx.call_void_method(fmv.payload_ctx_disconnect, &[msg.raw]).unwrap()
     }
 }
@@ -1,10 +1,10 @@
 use super::{
     cleaner::Cleanable,
     client::SolidRenderer,
-    geometry::GeomExt,
-    mapping::{CN, MN},
+    geometry::{write_dir, GeomExt},
+    mapping::{ForgeMN, CN, MN},
     nbt::{new_compound, NBTExt, KEY_SAVE, KEY_SYNC},
-    ClassBuilder, ClassNamer, FatWrapper,
+    strict_deserialize, ClassBuilder, ClassNamer, FatWrapper,
 };
 use crate::{
     asm::*,
@@ -14,10 +14,11 @@ use crate::{
     objs,
 };
 use alloc::{format, sync::Arc, vec::Vec};
-use anyhow::Result;
-use core::any::Any;
+use anyhow::{anyhow, bail, Result};
+use core::{any::Any, cmp::Ordering};
 use macros::dyn_abi;
 use nalgebra::{Affine3, Point2};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 impl<'a, T: JRef<'a>> TileExt<'a> for T {}
 pub trait TileExt<'a>: JRef<'a> {
@@ -25,6 +26,40 @@ pub trait TileExt<'a>: JRef<'a> {
     fn tile_pos(&self) -> LocalRef<'a> { self.get_object_field(objs().mv.tile_pos).unwrap() }
     fn tile_mark_for_save(&self) { self.call_void_method(objs().mv.tile_set_changed, &[]).unwrap() }
     fn block_state_get_block(&self) -> LocalRef<'a> { self.call_object_method(objs().mv.block_state_get_block, &[]).unwrap().unwrap() }
+    fn block_state_get_value(&self, prop: &GlobalRef) -> LocalRef<'a> {
+        self.call_object_method(objs().mv.state_holder_get_value, &[prop.raw]).unwrap().unwrap()
+    }
+
+    fn block_state_with_value(&self, prop: &GlobalRef, value: &impl JRef<'a>) -> LocalRef<'a> {
+        self.call_object_method(objs().mv.state_holder_set_value, &[prop.raw, value.raw()]).unwrap().unwrap()
+    }
+
+    fn block_state_get_facing(&self) -> u8 { self.block_state_get_value(&objs().mv.block_state_props_facing).read_dir() }
+    fn block_state_with_facing(&self, dir: u8) -> LocalRef<'a> {
+        self.block_state_with_value(&objs().mv.block_state_props_facing, &write_dir(self.jni(), dir))
+    }
+
+    fn block_state_is_waterlogged(&self) -> bool {
+        let GlobalObjs { av, mv, .. } = objs();
+        self.block_state_get_value(&mv.block_state_props_waterlogged).is_same_object(av.jv.wrap_bool(self.jni(), true).unwrap().raw)
+    }
+
+    fn block_state_with_waterlogged(&self, x: bool) -> LocalRef<'a> {
+        let GlobalObjs { av, mv, .. } = objs();
+        self.block_state_with_value(&mv.block_state_props_waterlogged, &av.jv.wrap_bool(self.jni(), x).unwrap())
+    }
+
+    fn block_state_get_level(&self) -> i32 {
+        let GlobalObjs { av, mv, .. } = objs();
+        self.block_state_get_value(&mv.block_state_props_level).int_value(&av.jv).unwrap()
+    }
+
+    fn block_state_with_level(&self, x: i32) -> LocalRef<'a> {
+        let mv = &objs().mv;
+        let x = mv.integer.with_jni(self.jni()).call_static_object_method(mv.integer_value_of, &[x as _]).unwrap().unwrap();
+        self.block_state_with_value(&mv.block_state_props_level, &x)
+    }
+
     fn block_state_at(&self, pos: &impl JRef<'a>) -> LocalRef<'a> {
         self.call_object_method(objs().mv.block_getter_get_block_state, &[pos.raw()]).unwrap().unwrap()
     }
@@ -36,6 +71,7 @@ pub trait TileExt<'a>: JRef<'a> {
     fn tile_at(&self, pos: &impl JRef<'a>) -> Option<LocalRef<'a>> { self.call_object_method(objs().mv.block_getter_get_tile, &[pos.raw()]).unwrap() }
     fn is_outside_build_height(&self, y: i32) -> bool { self.call_bool_method(objs().mv.level_is_outside_build_height, &[y as _]).unwrap() }
     fn level_is_client(&self) -> bool { self.get_bool_field(objs().mv.level_is_client) }
+    fn level_game_time(&self) -> i64 { self.call_long_method(objs().mv.level_get_game_time, &[]).unwrap() }
     fn level_get_chunk_source(&self) -> LocalRef<'a> { self.call_object_method(objs().mv.level_get_chunk_source, &[]).unwrap().unwrap() }
     fn level_mark_for_broadcast(&self, pos: &impl JRef<'a>) {
         self.level_get_chunk_source().call_void_method(objs().mv.server_chunk_cache_block_changed, &[pos.raw()]).unwrap()
@@ -48,12 +84,49 @@ pub trait TileSupplier: Send {
 
 pub trait Tile: Cleanable {
     fn any(&self) -> &dyn Any;
-    fn encode_save(&self) -> Vec<u8>;
+    // Tags that are missing when decoding keep their current values, as `/data merge` may leave some out.
+    fn encode_save(&self, tag: BorrowedRef);
     fn encode_sync(&self) -> Vec<u8>;
-    fn decode_save(&self, bytes: &[u8]) -> Result<()>;
+    fn decode_save(&self, tag: BorrowedRef) -> Result<()>;
+    fn decode_legacy_save(&self, bytes: &[u8]) -> Result<()>;
     fn decode_sync(&self, bytes: &[u8]) -> Result<()>;
     fn set_removed(&self, jni: &JNI, lk: &GlobalMtx);
-    fn render(&self, lk: &GlobalMtx, sr: SolidRenderer, tf: Affine3<f32>);
+    // Called when the block state changes without replacing the tile, e.g. when the block is rotated.
+    fn set_block_state(&self, lk: &GlobalMtx, state: BorrowedRef<'static, '_>);
+    // Called once the tile is in a level with its save data loaded, and again if it's loaded over, as by `/data merge`.
+    fn on_load(&self, lk: &GlobalMtx, tile: BorrowedRef<'static, '_>);
+    fn render(&self, lk: &GlobalMtx, sr: SolidRenderer, tf: Affine3<f32>, time: f32);
+}
+
+// Saves lead with the version of their layout. Older layouts are upgraded one version at a time.
+pub trait Versioned: Serialize + DeserializeOwned {
+    // One more than that of `Prev`, starting from 0. Versions that were ever saved must not be reused.
+    const VERSION: u32;
+    type Prev: Versioned;
+    fn upgrade(prev: Self::Prev) -> Self;
+
+    fn decode_version(version: u32, bytes: &[u8]) -> Result<Self> {
+        match version.cmp(&Self::VERSION) {
+            Ordering::Equal => strict_deserialize(bytes),
+            Ordering::Less => Self::Prev::decode_version(version, bytes).map(Self::upgrade),
+            Ordering::Greater => bail!("save data version {version} is newer than {}", Self::VERSION),
+        }
+    }
+
+    fn encode_versioned(&self) -> Vec<u8> { postcard::to_allocvec(&(Self::VERSION, self)).unwrap() }
+    fn decode_versioned(bytes: &[u8]) -> Result<Self> {
+        let (version, bytes) = postcard::take_from_bytes(bytes).map_err(|e| anyhow!("{e}"))?;
+        Self::decode_version(version, bytes)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum Unversioned {}
+impl Versioned for Unversioned {
+    const VERSION: u32 = 0;
+    type Prev = Self;
+    fn upgrade(prev: Self) -> Self { prev }
+    fn decode_version(version: u32, _: &[u8]) -> Result<Self> { bail!("unknown save data version {version}") }
 }
 
 pub struct TileDefs {
@@ -62,13 +135,15 @@ pub struct TileDefs {
 }
 
 impl TileDefs {
-    pub fn init(av: &AV<'static>, cn: &CN<Arc<CSig>>, mn: &MN<MSig>, namer: &ClassNamer) -> Self {
+    pub fn init(av: &AV<'static>, cn: &CN<Arc<CSig>>, mn: &MN<MSig>, fmn: &ForgeMN, namer: &ClassNamer) -> Self {
         let jni = av.ldr.jni;
         let tile = ClassBuilder::new_1(av, namer, &cn.tile.slash)
             .native_2(&mn.tile_get_update_tag, get_update_tag_dyn())
             .native_2(&mn.tile_save_additional, save_additional_dyn())
             .native_2(&mn.tile_load_additional, load_additional_dyn())
             .native_2(&mn.tile_set_removed, set_removed_dyn())
+            .native_2(&mn.tile_set_block_state, set_block_state_dyn())
+            .native_2(&fmn.tile_on_load, on_load_dyn())
             .insns(
                 &mn.tile_get_update_pkt,
                 [
@@ -129,31 +204,38 @@ fn save_additional(jni: &JNI, tile: usize, tag: usize, regs: usize) {
     let tag = BorrowedRef::new(jni, &tag);
     let tile = BorrowedRef::new(jni, &tile);
     tile.call_nonvirtual_void_method(mv.tile.raw, mv.tile_save_additional, &[tag.raw, regs]).unwrap();
-    tag.compound_put_byte_array(KEY_SAVE, &tile_defs.tile.read(&mtx.lock(jni).unwrap(), tile).encode_save());
+    tile_defs.tile.read(&mtx.lock(jni).unwrap(), tile).encode_save(tag);
 }
 
 #[dyn_abi]
-fn load_additional(jni: &JNI, tile: usize, nbt: usize, regs: usize) {
+fn load_additional(jni: &'static JNI, tile: usize, nbt: usize, regs: usize) {
     let GlobalObjs { mv, tile_defs, mtx, .. } = objs();
     let j_tile = BorrowedRef::new(jni, &tile);
     let tag = BorrowedRef::new(jni, &nbt);
     j_tile.call_nonvirtual_void_method(mv.tile.raw, mv.tile_load_additional, &[tag.raw, regs]).unwrap();
     let lk = mtx.lock(jni).unwrap();
     let tile = tile_defs.tile.read(&lk, j_tile);
+    // Update tags hold nothing but sync data, so any other tag is save data.
     let mut data = tag.compound_get_byte_array(KEY_SYNC);
     let mut buf = data.crit_elems().unwrap();
     let result = (!buf.is_empty()).then(|| tile.decode_sync(&*buf));
     drop(buf);
-    if let Some(Err(e)) = result {
-        warn(jni, &cs(format!("Failed to load sync data for tile at {}: {e:?}", j_tile.tile_pos().read_vec3i())))
+    if let Some(result) = result {
+        if let Err(e) = result {
+            warn(jni, &cs(format!("Failed to load sync data for tile at {}: {e:?}", j_tile.tile_pos().read_vec3i())))
+        }
+        return;
     }
     data = tag.compound_get_byte_array(KEY_SAVE);
     buf = data.crit_elems().unwrap();
-    let result = (!buf.is_empty()).then(|| tile.decode_save(&*buf));
+    let result = if buf.is_empty() { None } else { Some(tile.decode_legacy_save(&*buf)) };
     drop(buf);
-    if let Some(Err(e)) = result {
+    if let Err(e) = result.unwrap_or_else(|| tile.decode_save(tag)) {
         warn(jni, &cs(format!("Failed to load save data for tile at {}: {e:?}", j_tile.tile_pos().read_vec3i())))
     }
+    if j_tile.tile_level().is_some() {
+        tile.on_load(&lk, j_tile)
+    }
 }
 
 #[dyn_abi]
@@ -165,3 +247,19 @@ fn set_removed(jni: &JNI, this: usize) {
     drop(lk);
     this.call_nonvirtual_void_method(mv.tile.raw, mv.tile_set_removed, &[]).unwrap();
 }
+
+#[dyn_abi]
+fn on_load(jni: &'static JNI, this: usize) {
+    let lk = objs().mtx.lock(jni).unwrap();
+    let this = BorrowedRef::new(jni, &this);
+    objs().tile_defs.tile.read(&lk, this).on_load(&lk, this)
+}
+
+#[dyn_abi]
+fn set_block_state(jni: &'static JNI, this: usize, state: usize) {
+    let GlobalObjs { mv, tile_defs, mtx, .. } = objs();
+    let this = BorrowedRef::new(jni, &this);
+    this.call_nonvirtual_void_method(mv.tile.raw, mv.tile_set_block_state, &[state]).unwrap();
+    let lk = mtx.lock(jni).unwrap();
+    tile_defs.tile.read(&lk, this).set_block_state(&lk, BorrowedRef::new(jni, &state))
+}
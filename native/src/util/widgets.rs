@@ -0,0 +1,366 @@
+use super::{
+    client::{client_ticks, play_btn_click_sound, ClientExt},
+    geometry::Rect,
+    gui::{GUIExt, KEY_BACKSPACE, KEY_ENTER, KEY_ESCAPE, KEY_KP_ENTER, KEY_SPACE, KEY_TAB},
+    tessellator::{Rounding, Stroke, Tessellator},
+};
+use crate::{jvm::*, mapping_base::cs};
+use alloc::{format, string::String, vec::Vec};
+use core::{
+    cell::{Cell, RefCell},
+    ffi::CStr,
+    mem::take,
+};
+use nalgebra::{point, vector, Point2, Vector4};
+
+pub enum Kind {
+    Label(&'static CStr),
+    Text { key: &'static CStr, args: Vec<Arg> },
+    Button(&'static CStr),
+    Toggle { label: &'static CStr, on: bool },
+    Slider { value: f32, min: f32, max: f32 },
+    Field { value: f32, unit: &'static CStr, parse: fn(&str) -> Option<f32> },
+    Entry { text: String, max_len: usize },
+}
+
+#[derive(Clone)]
+pub enum Arg {
+    Literal(String),
+    Translated(String),
+}
+
+pub enum Action {
+    Clicked,
+    Toggled(bool),
+    Changed(f32),
+    Entered(String),
+}
+
+pub enum Response<I> {
+    Ignored,
+    Consumed,
+    Action(I, Action),
+}
+
+impl<I> Response<I> {
+    pub fn is_handled(&self) -> bool { !matches!(self, Self::Ignored) }
+}
+
+struct Widget<I> {
+    id: I,
+    rect: Rect,
+    kind: Kind,
+}
+
+pub struct Widgets<I> {
+    items: RefCell<Vec<Widget<I>>>,
+    pressed: Cell<Option<usize>>,
+    focused: Cell<Option<usize>>,
+    editing: RefCell<Option<(usize, String)>>,
+}
+
+pub struct Layout {
+    pos: Point2<f32>,
+    spacing: f32,
+}
+
+impl Layout {
+    pub fn new(pos: Point2<f32>, spacing: f32) -> Self { Self { pos, spacing } }
+    pub fn row<const N: usize>(&mut self, height: f32, widths: [f32; N]) -> [Rect; N] {
+        let mut x = self.pos.x;
+        let rects = widths.map(|width| {
+            let rect = Rect { min: point![x, self.pos.y], max: point![x + width, self.pos.y + height] };
+            x += width + self.spacing;
+            rect
+        });
+        self.pos.y += height + self.spacing;
+        rects
+    }
+}
+
+const NORMAL: Vector4<f32> = vector![1., 1., 1., 0.5];
+const HOVERED: Vector4<f32> = vector![0.8, 0.8, 1., 0.5];
+const PRESSED: Vector4<f32> = vector![0.5, 0.5, 0.5, 0.5];
+const EDITING: Vector4<f32> = vector![1., 1., 1., 0.9];
+const INVALID_TEXT: i32 = 0xC00000;
+
+fn toggle_box(rect: &Rect) -> Rect { Rect::from_center_size(point![rect.min.x + 4., rect.center().y], vector![8., 8.]) }
+fn slider_value(rect: &Rect, x: f32, min: f32, max: f32) -> f32 { min + ((x - rect.min.x - 2.) / (rect.width() - 4.)).clamp(0., 1.) * (max - min) }
+fn is_focusable(kind: &Kind) -> bool { matches!(kind, Kind::Button(_) | Kind::Toggle { .. } | Kind::Field { .. } | Kind::Entry { .. }) }
+fn field_text(kind: &Kind) -> Option<String> {
+    match kind {
+        Kind::Field { value, .. } => Some(format!("{value:.2}")),
+        Kind::Entry { text, .. } => Some(text.clone()),
+        _ => None,
+    }
+}
+
+impl<I> Default for Widgets<I> {
+    fn default() -> Self { Self { items: <_>::default(), pressed: None.into(), focused: None.into(), editing: None.into() } }
+}
+
+impl<I: Copy + PartialEq> Widgets<I> {
+    pub fn with(mut self, id: I, rect: Rect, kind: Kind) -> Self {
+        self.items.get_mut().push(Widget { id, rect, kind });
+        self
+    }
+
+    pub fn set_value(&self, id: I, x: f32) {
+        for widget in self.items.borrow_mut().iter_mut().filter(|w| w.id == id) {
+            if let Kind::Slider { value, .. } | Kind::Field { value, .. } = &mut widget.kind {
+                *value = x
+            }
+        }
+    }
+
+    pub fn set_on(&self, id: I, x: bool) {
+        for widget in self.items.borrow_mut().iter_mut().filter(|w| w.id == id) {
+            if let Kind::Toggle { on, .. } = &mut widget.kind {
+                *on = x
+            }
+        }
+    }
+
+    pub fn set_label(&self, id: I, new_key: &'static CStr) {
+        for widget in self.items.borrow_mut().iter_mut().filter(|w| w.id == id) {
+            if let Kind::Button(key) = &mut widget.kind {
+                *key = new_key
+            }
+        }
+    }
+
+    pub fn set_entry(&self, id: I, new_text: &str) {
+        for widget in self.items.borrow_mut().iter_mut().filter(|w| w.id == id) {
+            if let Kind::Entry { text, .. } = &mut widget.kind {
+                new_text.clone_into(text)
+            }
+        }
+    }
+
+    pub fn set_text(&self, id: I, new_key: &'static CStr, new_args: Vec<Arg>) {
+        for widget in self.items.borrow_mut().iter_mut().filter(|w| w.id == id) {
+            if let Kind::Text { key, args } = &mut widget.kind {
+                (*key, *args) = (new_key, new_args.clone())
+            }
+        }
+    }
+
+    pub fn is_focused(&self) -> bool { self.editing.borrow().is_some() }
+
+    pub fn focused(&self) -> Option<I> {
+        let i = self.editing.borrow().as_ref().map(|x| x.0).or(self.focused.get())?;
+        Some(self.items.borrow()[i].id)
+    }
+
+    pub fn render(&self, screen: BorrowedRef, gui: BorrowedRef, origin: Point2<f32>, cursor: Point2<i32>) {
+        let jni = gui.jni;
+        let items = self.items.borrow();
+        let editing = self.editing.borrow();
+        let cursor = cursor.cast::<f32>() - origin.coords;
+        let black = Stroke::new(1., vector![0., 0., 0., 1.]);
+        let state_fill = |i: usize, rect: &Rect| match self.pressed.get() {
+            Some(x) if x == i => PRESSED,
+            None if rect.contains(cursor) || self.focused.get() == Some(i) => HOVERED,
+            _ => NORMAL,
+        };
+        let edited = |i: usize| editing.as_ref().filter(|x| x.0 == i).map(|x| &*x.1);
+        let invalid = |i: usize, parse: fn(&str) -> Option<f32>| edited(i).is_some_and(|x| parse(x).is_none());
+
+        let mut tess = Tessellator::new(jni);
+        for (i, widget) in items.iter().enumerate() {
+            let rect = widget.rect.translate(origin.coords);
+            match widget.kind {
+                Kind::Label(_) | Kind::Text { .. } => (),
+                Kind::Button(_) => tess.rect(rect, Rounding::same(2.), 0., state_fill(i, &widget.rect), &black),
+                Kind::Toggle { on, .. } => {
+                    let cb_rect = toggle_box(&rect);
+                    tess.rect(cb_rect, Rounding::same(1.), 0., state_fill(i, &widget.rect), &black);
+                    if on {
+                        let center = cb_rect.center();
+                        let pts = [center + vector![-2.7, 0.5], center + vector![-0.7, 2.7], center + vector![2.7, -2.7]];
+                        tess.path(&pts, false, Vector4::zeros(), &black)
+                    }
+                }
+                Kind::Slider { value, min, max } => {
+                    tess.line([rect.left_center() + vector![2., 0.], rect.right_center() - vector![2., 0.]], &black);
+                    let x = rect.min.x + 2. + ((value - min) / (max - min)).clamp(0., 1.) * (rect.width() - 4.);
+                    let knob = Rect { min: point![x - 2., rect.min.y], max: point![x + 2., rect.max.y] };
+                    tess.rect(knob, Rounding::same(1.), 0., state_fill(i, &widget.rect), &black)
+                }
+                Kind::Field { parse, .. } => {
+                    let fill = if edited(i).is_some() { EDITING } else { NORMAL };
+                    let stroke = if invalid(i, parse) { Stroke::new(1., vector![0.8, 0., 0., 1.]) } else { Stroke::new(1., black.color) };
+                    tess.rect(rect, Rounding::same(1.), 0., fill, &stroke)
+                }
+                Kind::Entry { .. } => tess.rect(rect, Rounding::same(1.), 0., if edited(i).is_some() { EDITING } else { NORMAL }, &black),
+            }
+        }
+        gui.gui_draw_mesh(&mut tess.mesh);
+
+        let font = screen.screen_font();
+        let draw = |text: &LocalRef, pos: Point2<f32>, color: i32| gui.gui_draw_formatted(&font, text, pos.x as _, pos.y as _, color, false);
+        for (i, widget) in items.iter().enumerate() {
+            let rect = widget.rect.translate(origin.coords);
+            let text_pos = |x: f32| point![x, rect.center().y - 4.];
+            match widget.kind {
+                Kind::Label(key) => draw(&jni.new_utf(key).unwrap().translatable().to_formatted(), text_pos(rect.min.x), 0),
+                Kind::Text { key, ref args } => {
+                    let args = args.iter().map(|x| match x {
+                        Arg::Literal(x) => jni.new_utf(&cs(x.as_str())).unwrap(),
+                        Arg::Translated(x) => jni.new_utf(&cs(x.as_str())).unwrap().translatable(),
+                    });
+                    let args = args.collect::<Vec<_>>();
+                    draw(&jni.new_utf(key).unwrap().translatable_with(&args).to_formatted(), text_pos(rect.min.x), 0)
+                }
+                Kind::Button(key) => {
+                    let text = jni.new_utf(key).unwrap().translatable().to_formatted();
+                    let width = font.font_width(&text) as f32;
+                    draw(&text, text_pos(rect.center().x - width * 0.5), 0)
+                }
+                Kind::Toggle { label, .. } => draw(&jni.new_utf(label).unwrap().translatable().to_formatted(), text_pos(rect.min.x + 12.), 0),
+                Kind::Slider { .. } => (),
+                Kind::Field { parse, unit, .. } => {
+                    let text = match edited(i) {
+                        Some(text) => format!("{text}{}", if client_ticks(jni) / 6 % 2 == 0 { "_" } else { "" }),
+                        None => field_text(&widget.kind).unwrap(),
+                    };
+                    let color = if invalid(i, parse) { INVALID_TEXT } else { 0 };
+                    draw(&jni.new_utf(&cs(text)).unwrap().literal().to_formatted(), text_pos(rect.min.x + 3.), color);
+                    draw(&jni.new_utf(unit).unwrap().literal().to_formatted(), text_pos(rect.max.x + 2.), 0)
+                }
+                Kind::Entry { ref text, .. } => {
+                    let fits = |x: &str| font.font_width(&jni.new_utf(&cs(x)).unwrap().literal().to_formatted()) as f32 <= rect.width() - 6.;
+                    let text = match edited(i) {
+                        Some(text) => {
+                            let mut start = text.char_indices().map(|(i, _)| i).chain([text.len()]);
+                            let text = start.find_map(|i| Some(&text[i..]).filter(|x| fits(x))).unwrap_or_default();
+                            format!("{text}{}", if client_ticks(jni) / 6 % 2 == 0 { "_" } else { "" })
+                        }
+                        None => {
+                            let mut end = text.char_indices().map(|(i, _)| i).chain([text.len()]).rev();
+                            end.find_map(|i| Some(&text[..i]).filter(|x| fits(x))).unwrap_or_default().into()
+                        }
+                    };
+                    draw(&jni.new_utf(&cs(text)).unwrap().literal().to_formatted(), text_pos(rect.min.x + 3.), 0)
+                }
+            }
+        }
+    }
+
+    fn hit(items: &[Widget<I>], pos: Point2<f32>) -> Option<usize> {
+        items.iter().position(|x| !matches!(x.kind, Kind::Label(_) | Kind::Text { .. }) && x.rect.contains(pos))
+    }
+
+    pub fn mouse_clicked(&self, jni: &JNI, origin: Point2<f32>, pos: Point2<f32>, button: i32) -> Response<I> {
+        let pos = pos - origin.coords;
+        let items = self.items.borrow();
+        let hit = if button == 0 { Self::hit(&items, pos) } else { None };
+        self.focused.set(hit.filter(|&i| is_focusable(&items[i].kind)));
+        let mut editing = self.editing.borrow_mut();
+        if editing.as_ref().map(|x| x.0) != hit {
+            *editing = try { (hit?, field_text(&items[hit?].kind)?) }
+        }
+        let Some(i) = hit else { return Response::Ignored };
+        play_btn_click_sound(jni);
+        let widget = &items[i];
+        match widget.kind {
+            Kind::Field { .. } | Kind::Entry { .. } => Response::Consumed,
+            Kind::Slider { min, max, .. } => {
+                self.pressed.set(Some(i));
+                Response::Action(widget.id, Action::Changed(slider_value(&widget.rect, pos.x, min, max)))
+            }
+            _ => {
+                self.pressed.set(Some(i));
+                Response::Consumed
+            }
+        }
+    }
+
+    pub fn mouse_dragged(&self, origin: Point2<f32>, pos: Point2<f32>) -> Response<I> {
+        let Some(i) = self.pressed.get() else { return Response::Ignored };
+        let widget = &self.items.borrow()[i];
+        let Kind::Slider { min, max, .. } = widget.kind else { return Response::Consumed };
+        Response::Action(widget.id, Action::Changed(slider_value(&widget.rect, pos.x - origin.x, min, max)))
+    }
+
+    pub fn mouse_released(&self, origin: Point2<f32>, pos: Point2<f32>, button: i32) -> Response<I> {
+        let 0 = button else { return Response::Ignored };
+        let Some(i) = self.pressed.take() else { return Response::Ignored };
+        let widget = &self.items.borrow()[i];
+        let true = widget.rect.contains(pos - origin.coords) else { return Response::Consumed };
+        match widget.kind {
+            Kind::Button(_) => Response::Action(widget.id, Action::Clicked),
+            Kind::Toggle { on, .. } => Response::Action(widget.id, Action::Toggled(!on)),
+            _ => Response::Consumed,
+        }
+    }
+
+    pub fn key_pressed(&self, key: i32) -> Response<I> {
+        let items = self.items.borrow();
+        let mut editing = self.editing.borrow_mut();
+        if let Some((i, text)) = &mut *editing {
+            match key {
+                KEY_ESCAPE => *editing = None,
+                KEY_BACKSPACE => {
+                    text.pop();
+                }
+                KEY_ENTER | KEY_KP_ENTER | KEY_TAB => {
+                    let i = *i;
+                    let action = match items[i].kind {
+                        Kind::Field { parse, .. } => match parse(text) {
+                            Some(value) => Action::Changed(value),
+                            None => return Response::Consumed,
+                        },
+                        Kind::Entry { .. } => Action::Entered(take(text)),
+                        _ => unreachable!(),
+                    };
+                    *editing = None;
+                    if key == KEY_TAB {
+                        self.focus_next(&items, &mut editing)
+                    }
+                    return Response::Action(items[i].id, action);
+                }
+                _ => return Response::Ignored,
+            }
+            return Response::Consumed;
+        }
+        match key {
+            KEY_TAB => self.focus_next(&items, &mut editing),
+            KEY_ESCAPE if self.focused.get().is_some() => self.focused.set(None),
+            KEY_ENTER | KEY_KP_ENTER | KEY_SPACE => {
+                let Some(i) = self.focused.get() else { return Response::Ignored };
+                let widget = &items[i];
+                match widget.kind {
+                    Kind::Button(_) => return Response::Action(widget.id, Action::Clicked),
+                    Kind::Toggle { on, .. } => return Response::Action(widget.id, Action::Toggled(!on)),
+                    _ => *editing = Some((i, field_text(&widget.kind).unwrap())),
+                }
+            }
+            _ => return Response::Ignored,
+        }
+        Response::Consumed
+    }
+
+    fn focus_next(&self, items: &[Widget<I>], editing: &mut Option<(usize, String)>) {
+        let start = self.focused.get().map_or(0, |x| x + 1);
+        let next = (start..items.len()).chain(0..start).find(|&i| is_focusable(&items[i].kind));
+        self.focused.set(next);
+        *editing = try { (next?, field_text(&items[next?].kind)?) }
+    }
+
+    pub fn char_typed(&self, ch: char) -> Response<I> {
+        let mut editing = self.editing.borrow_mut();
+        let Some((i, text)) = &mut *editing else { return Response::Ignored };
+        let (ok, max_len) = match self.items.borrow()[*i].kind {
+            Kind::Entry { max_len, .. } => (!ch.is_control(), max_len),
+            _ => (ch.is_ascii_digit() || ch == '.' || ch == '-', 10),
+        };
+        if !ok {
+            return Response::Ignored;
+        }
+        if text.chars().count() < max_len {
+            text.push(ch)
+        }
+        Response::Consumed
+    }
+}